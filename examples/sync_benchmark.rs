@@ -0,0 +1,106 @@
+//! compares syncing the inspected entities incrementally, via the observed archetype changes in [`EntityChanges`], with diffing every entity every frame, in a large world that spawns and despawns a few entities each frame; frame times include the spawning and despawning, so the incremental times also pay for the observers
+//!
+//! `cargo run --release --example sync_benchmark`
+
+use std::time::{Duration, Instant};
+
+use aalo::inspector::{
+    sync_changed_entities_helper, sync_entities_helper, track_entity_changes, EntityChanges,
+    EntityData, FieldPathCache,
+};
+use bevy::{core::NameOrEntity, prelude::*};
+use haalka::prelude::*;
+
+const ENTITIES: usize = 20_000;
+const CHURN: usize = 10;
+const FRAMES: u32 = 500;
+
+fn main() {
+    let full = benchmark(false);
+    let incremental = benchmark(true);
+    println!("{ENTITIES} entities, {CHURN} spawned and despawned each frame, {FRAMES} frames");
+    println!("full diff:   {:?} per frame", full / FRAMES);
+    println!("incremental: {:?} per frame", incremental / FRAMES);
+    println!(
+        "speedup:     {:.1}x",
+        full.as_secs_f64() / incremental.as_secs_f64()
+    );
+}
+
+#[derive(Resource)]
+struct Synced {
+    entities: MutableBTreeMap<Entity, EntityData>,
+    incremental: bool,
+}
+
+#[derive(Component)]
+struct Churn;
+
+fn benchmark(incremental: bool) -> Duration {
+    let mut app = App::new();
+    app.init_resource::<FieldPathCache>()
+        .init_resource::<EntityChanges>()
+        .insert_resource(Synced {
+            entities: MutableBTreeMap::new(),
+            incremental,
+        });
+    if incremental {
+        app.add_systems(Update, (churn, track_entity_changes, sync).chain());
+    } else {
+        app.add_systems(Update, (churn, sync).chain());
+    }
+    let world = app.world_mut();
+    for i in 0..ENTITIES {
+        world.spawn((Name::new(format!("entity {i}")), Transform::default()));
+    }
+    for _ in 0..CHURN {
+        world.spawn(Churn);
+    }
+    // the first sync diffs every entity either way
+    app.update();
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        app.update();
+    }
+    let elapsed = start.elapsed();
+    assert_eq!(
+        app.world().resource::<Synced>().entities.lock_ref().len(),
+        app.world().entities().len() as usize
+    );
+    elapsed
+}
+
+fn churn(churned: Query<Entity, With<Churn>>, mut commands: Commands) {
+    for entity in churned.iter() {
+        commands.entity(entity).despawn();
+        commands.spawn(Churn);
+    }
+}
+
+fn sync(
+    query: Query<Entity>,
+    entity_changes: Res<EntityChanges>,
+    mut last_generation: Local<Option<u64>>,
+    debug_names: Query<NameOrEntity>,
+    mut field_path_cache: ResMut<FieldPathCache>,
+    synced: Res<Synced>,
+) {
+    if synced.incremental {
+        if let Some(changed) = entity_changes.changed_since(&mut last_generation) {
+            sync_changed_entities_helper(
+                &synced.entities,
+                &query,
+                changed,
+                &debug_names,
+                &mut field_path_cache,
+            );
+            return;
+        }
+    }
+    sync_entities_helper(
+        &synced.entities,
+        &query,
+        &debug_names,
+        &mut field_path_cache,
+    );
+}
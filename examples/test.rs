@@ -1,6 +1,6 @@
 #![allow(clippy::all)]
 
-use aalo::prelude::*;
+use aalo::{attributes, prelude::*};
 use bevy::prelude::*;
 use bevy_math::Vec3A;
 use haalka::prelude::*;
@@ -55,6 +55,13 @@ fn main() {
                     //     "bevy_window::window::Window",
                     //     ".resolution",
                     // ))
+                    // .query_filter::<(With<Node>, Without<Text>)>()
+                    // .without_component::<Camera>()
+                    // .deny_component::<GlobalTransform>()
+                    // .watch(("resource", "bevy_time::time::Time", ".elapsed"))
+                    // .skip_unchanged_edits()
+                    // .silent_edits()
+                    // .time_controls()
                     // .with_entities(|entities| {
                     //     entities
                     //         .filter_signal_cloned(|&(entity, _)| {
@@ -97,6 +104,9 @@ fn main() {
         .register_type::<MatrixHolder>()
         .register_type::<BoolVecHolder>()
         .register_type::<NonZeroHolder>()
+        .register_type::<AttributesHolder>()
+        .add_event::<TestEvent>()
+        .register_reflect_event::<TestEvent>()
         .add_systems(Startup, (camera, ui_root, setup))
         .add_systems(Update, toggle_overlay)
         .run();
@@ -165,6 +175,26 @@ struct BoolVecHolder(pub BVec2);
 #[derive(Component, Reflect)]
 struct NonZeroHolder(pub std::num::NonZeroI64);
 
+#[derive(Component, Reflect, Default)]
+struct AttributesHolder {
+    #[reflect(@attributes::Range(0.0..=1.0), @attributes::Step(0.05), @attributes::Slider)]
+    volume: f32,
+    #[reflect(@attributes::Range(0.0..=10.0), @attributes::Unit("m"))]
+    distance: u8,
+    #[reflect(@attributes::DisplayName("locked"), @attributes::ReadOnly)]
+    read_only: bool,
+    #[reflect(@attributes::Hidden)]
+    hidden: bool,
+    #[reflect(@attributes::Multiline)]
+    dialogue: String,
+}
+
+#[derive(Event, Reflect, Default)]
+struct TestEvent {
+    damage: f32,
+    critical: bool,
+}
+
 /// set up a simple 3D scene
 fn setup(
     mut commands: Commands,
@@ -182,6 +212,7 @@ fn setup(
         },
         Name::new("BoolComponentHolder"),
     ));
+    commands.spawn((AttributesHolder::default(), Name::new("AttributesHolder")));
 
     // // plane
     // commands.spawn((
@@ -0,0 +1,89 @@
+// custom reflect attributes for configuring how fields are presented, e.g. `#[reflect(@Range(0.0..=1.0), @Step(0.05), @Slider)]`
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{attributes::CustomAttributes, Reflect};
+use std::ops::RangeInclusive;
+
+/// Clamp numeric fields to this range.
+#[derive(Reflect, Clone, Debug)]
+pub struct Range(pub RangeInclusive<f64>);
+
+/// Snap numeric fields to multiples of this step, offset by the start of the [`Range`] if any.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct Step(pub f64);
+
+/// Render numeric fields with a slider; requires a [`Range`].
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct Slider;
+
+/// Edit string fields in a growing, wrapping text area; ctrl+enter commits.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct Multiline;
+
+/// Disallow editing the field, and any fields nested in it, from the inspector.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct ReadOnly;
+
+/// Don't show the field in the inspector.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct Hidden;
+
+/// Show this name in place of the field's name.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct DisplayName(pub &'static str);
+
+/// Show this unit next to the field's name.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct Unit(pub &'static str);
+
+#[derive(Component, Clone, Default, Debug)]
+pub struct FieldAttributes {
+    pub range: Option<RangeInclusive<f64>>,
+    pub step: Option<f64>,
+    pub slider: bool,
+    pub multiline: bool,
+    pub read_only: bool,
+    pub hidden: bool,
+    pub display_name: Option<&'static str>,
+    pub unit: Option<&'static str>,
+}
+
+impl FieldAttributes {
+    pub fn constrains_numeric(&self) -> bool {
+        self.range.is_some() || self.step.is_some()
+    }
+
+    pub fn constrain(&self, mut x: f64) -> f64 {
+        if let Some(step) = self.step.filter(|&step| step > 0.) {
+            let offset = self
+                .range
+                .as_ref()
+                .map(|range| *range.start())
+                .unwrap_or_default();
+            x = offset + ((x - offset) / step).round() * step;
+        }
+        if let Some(range) = &self.range {
+            if range.start() <= range.end() {
+                x = x.clamp(*range.start(), *range.end());
+            }
+        }
+        x
+    }
+}
+
+impl From<&CustomAttributes> for FieldAttributes {
+    fn from(attributes: &CustomAttributes) -> Self {
+        Self {
+            range: attributes.get::<Range>().map(|Range(range)| range.clone()),
+            step: attributes.get::<Step>().map(|&Step(step)| step),
+            slider: attributes.contains::<Slider>(),
+            multiline: attributes.contains::<Multiline>(),
+            read_only: attributes.contains::<ReadOnly>(),
+            hidden: attributes.contains::<Hidden>(),
+            display_name: attributes
+                .get::<DisplayName>()
+                .map(|&DisplayName(name)| name),
+            unit: attributes.get::<Unit>().map(|&Unit(unit)| unit),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// pluggable clipboard used by the inspector's copy/paste hotkeys, see [`ClipboardBackend`]
+
+use bevy_ecs::prelude::*;
+#[cfg(all(feature = "system_clipboard", not(target_arch = "wasm32")))]
+use bevy_log::prelude::*;
+
+/// Somewhere to put and take text from, e.g. the system clipboard.
+pub trait ClipboardBackend: Send + Sync + 'static {
+    fn get(&mut self) -> Option<String>;
+    fn set(&mut self, text: String);
+}
+
+/// Clipboard that only lives in memory, used when the `system_clipboard` feature is disabled.
+#[derive(Default, Debug)]
+pub struct InMemoryClipboard(Option<String>);
+
+impl ClipboardBackend for InMemoryClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.0.clone()
+    }
+
+    fn set(&mut self, text: String) {
+        self.0 = Some(text);
+    }
+}
+
+#[cfg(all(feature = "system_clipboard", not(target_arch = "wasm32")))]
+pub struct SystemClipboard(arboard::Clipboard);
+
+#[cfg(all(feature = "system_clipboard", not(target_arch = "wasm32")))]
+impl SystemClipboard {
+    pub fn new() -> Option<Self> {
+        arboard::Clipboard::new()
+            .inspect_err(|e| warn!("failed to access the system clipboard: {e}"))
+            .ok()
+            .map(Self)
+    }
+}
+
+#[cfg(all(feature = "system_clipboard", not(target_arch = "wasm32")))]
+impl ClipboardBackend for SystemClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.0.get_text().ok()
+    }
+
+    fn set(&mut self, text: String) {
+        if let Err(e) = self.0.set_text(text) {
+            warn!("failed to write to the system clipboard: {e}");
+        }
+    }
+}
+
+/// The clipboard used by the inspector; insert this resource to swap out the backend.
+#[derive(Resource)]
+pub struct Clipboard(Box<dyn ClipboardBackend>);
+
+impl Clipboard {
+    pub fn new(backend: impl ClipboardBackend) -> Self {
+        Self(Box::new(backend))
+    }
+
+    pub fn get(&mut self) -> Option<String> {
+        self.0.get()
+    }
+
+    pub fn set(&mut self, text: impl Into<String>) {
+        self.0.set(text.into());
+    }
+}
+
+impl Default for Clipboard {
+    fn default() -> Self {
+        #[cfg(all(feature = "system_clipboard", not(target_arch = "wasm32")))]
+        if let Some(system_clipboard) = SystemClipboard::new() {
+            return Self::new(system_clipboard);
+        }
+        Self::new(InMemoryClipboard::default())
+    }
+}
@@ -0,0 +1,380 @@
+// minimal math expression evaluator for numeric fields, e.g. `2*pi`, `1920/16`, `sqrt(2)/2`, `+=10`
+
+use std::{
+    f64::consts::{PI, TAU},
+    fmt,
+    iter::Peekable,
+    str::CharIndices,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExpressionError {
+    Empty,
+    UnexpectedCharacter(char, usize),
+    UnexpectedToken(usize),
+    UnexpectedEnd,
+    UnknownIdentifier(String),
+    InvalidNumber(String),
+    NotFinite,
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty expression"),
+            Self::UnexpectedCharacter(c, i) => write!(f, "unexpected '{c}' at {i}"),
+            Self::UnexpectedToken(i) => write!(f, "unexpected token at {i}"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            Self::UnknownIdentifier(identifier) => write!(f, "unknown identifier '{identifier}'"),
+            Self::InvalidNumber(number) => write!(f, "invalid number '{number}'"),
+            Self::NotFinite => write!(f, "result is not finite"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+impl ExpressionError {
+    // positions of errors in a subexpression starting at `offset`
+    fn offset(self, offset: usize) -> Self {
+        match self {
+            Self::UnexpectedCharacter(c, i) => Self::UnexpectedCharacter(c, i + offset),
+            Self::UnexpectedToken(i) => Self::UnexpectedToken(i + offset),
+            other => other,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<(Token, usize)>, ExpressionError> {
+    let mut tokens = vec![];
+    let mut chars: Peekable<CharIndices> = expression.char_indices().peekable();
+    while let Some(&(i, c)) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '0'..='9' | '.' => {
+                let mut number = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if c.is_ascii_digit() || c == '.' || c == '_' {
+                        if c != '_' {
+                            number.push(c);
+                        }
+                        chars.next();
+                    } else if (c == 'e' || c == 'E') && !number.contains(['e', 'E']) {
+                        // exponent, e.g. `1e-3`
+                        number.push(c);
+                        chars.next();
+                        if let Some(&(_, sign @ ('+' | '-'))) = chars.peek() {
+                            number.push(sign);
+                            chars.next();
+                        }
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push((
+                    Token::Number(
+                        number
+                            .parse()
+                            .map_err(|_| ExpressionError::InvalidNumber(number))?,
+                    ),
+                    i,
+                ));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut identifier = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        identifier.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push((Token::Identifier(identifier.to_lowercase()), i));
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '^' => Token::Caret,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            c => return Err(ExpressionError::UnexpectedCharacter(c, i)),
+        };
+        tokens.push((token, i));
+        chars.next();
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<(Token, usize)> {
+        let next = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        next
+    }
+
+    fn unexpected(&self, (_, i): (Token, usize)) -> ExpressionError {
+        ExpressionError::UnexpectedToken(i)
+    }
+
+    // expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<f64, ExpressionError> {
+        let mut value = self.term()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.next();
+                    value += self.term()?;
+                }
+                Some(Token::Minus) => {
+                    self.next();
+                    value -= self.term()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    // term := unary (('*' | '/' | '%') unary)*
+    fn term(&mut self) -> Result<f64, ExpressionError> {
+        let mut value = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::Star) => {
+                    self.next();
+                    value *= self.unary()?;
+                }
+                Some(Token::Slash) => {
+                    self.next();
+                    value /= self.unary()?;
+                }
+                Some(Token::Percent) => {
+                    self.next();
+                    value %= self.unary()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    // unary := ('+' | '-') unary | power
+    fn unary(&mut self) -> Result<f64, ExpressionError> {
+        match self.peek() {
+            Some(Token::Minus) => {
+                self.next();
+                Ok(-self.unary()?)
+            }
+            Some(Token::Plus) => {
+                self.next();
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+
+    // power := primary ('^' unary)?, right associative
+    fn power(&mut self) -> Result<f64, ExpressionError> {
+        let base = self.primary()?;
+        if let Some(Token::Caret) = self.peek() {
+            self.next();
+            return Ok(base.powf(self.unary()?));
+        }
+        Ok(base)
+    }
+
+    // primary := number | constant | function '(' expression ')' | '(' expression ')'
+    fn primary(&mut self) -> Result<f64, ExpressionError> {
+        match self.next() {
+            Some((Token::Number(number), _)) => Ok(number),
+            Some((Token::Identifier(identifier), _)) => match identifier.as_str() {
+                "pi" => Ok(PI),
+                "tau" => Ok(TAU),
+                "sqrt" | "sin" | "cos" => {
+                    let argument = self.parenthesized()?;
+                    Ok(match identifier.as_str() {
+                        "sqrt" => argument.sqrt(),
+                        "sin" => argument.sin(),
+                        _ => argument.cos(),
+                    })
+                }
+                _ => Err(ExpressionError::UnknownIdentifier(identifier)),
+            },
+            Some((Token::LeftParen, _)) => {
+                self.position -= 1;
+                self.parenthesized()
+            }
+            Some(other) => Err(self.unexpected(other)),
+            None => Err(ExpressionError::UnexpectedEnd),
+        }
+    }
+
+    fn parenthesized(&mut self) -> Result<f64, ExpressionError> {
+        match self.next() {
+            Some((Token::LeftParen, _)) => (),
+            Some(other) => return Err(self.unexpected(other)),
+            None => return Err(ExpressionError::UnexpectedEnd),
+        }
+        let value = self.expression()?;
+        match self.next() {
+            Some((Token::RightParen, _)) => Ok(value),
+            Some(other) => Err(self.unexpected(other)),
+            None => Err(ExpressionError::UnexpectedEnd),
+        }
+    }
+}
+
+/// Evaluate an arithmetic expression supporting `+ - * / % ^`, parentheses, `pi`, `tau`, `sqrt`, `sin`, and `cos`.
+pub fn evaluate(expression: &str) -> Result<f64, ExpressionError> {
+    let tokens = tokenize(expression)?;
+    if tokens.is_empty() {
+        return Err(ExpressionError::Empty);
+    }
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let value = parser.expression()?;
+    if let Some(next) = parser.next() {
+        return Err(parser.unexpected(next));
+    }
+    if !value.is_finite() {
+        return Err(ExpressionError::NotFinite);
+    }
+    Ok(value)
+}
+
+/// Like [`evaluate`], but also supports relative operations on `current`, e.g. `+=10`, `-=1`, `*=2`, `/=2`.
+pub fn evaluate_relative(expression: &str, current: f64) -> Result<f64, ExpressionError> {
+    let trimmed = expression.trim_start();
+    let mut chars = trimmed.chars();
+    if let (Some(op @ ('+' | '-' | '*' | '/' | '%' | '^')), Some('=')) =
+        (chars.next(), chars.next())
+    {
+        let offset = expression.len() - trimmed.len() + 2;
+        let operand = evaluate(&trimmed[2..]).map_err(|e| e.offset(offset))?;
+        let value = match op {
+            '+' => current + operand,
+            '-' => current - operand,
+            '*' => current * operand,
+            '/' => current / operand,
+            '%' => current % operand,
+            _ => current.powf(operand),
+        };
+        if !value.is_finite() {
+            return Err(ExpressionError::NotFinite);
+        }
+        return Ok(value);
+    }
+    evaluate(expression)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        assert_eq!(evaluate("1 + 2 * 3"), Ok(7.));
+        assert_eq!(evaluate("(1 + 2) * 3"), Ok(9.));
+        assert_eq!(evaluate("10 - 4 / 2 % 3"), Ok(8.));
+        assert_eq!(evaluate("2 * 3 ^ 2"), Ok(18.));
+        assert_eq!(evaluate("1920/16"), Ok(120.));
+    }
+
+    #[test]
+    fn power_is_right_associative() {
+        assert_eq!(evaluate("2 ^ 3 ^ 2"), Ok(512.));
+        assert_eq!(evaluate("2 ^ -1"), Ok(0.5));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(evaluate("-3"), Ok(-3.));
+        assert_eq!(evaluate("--3"), Ok(3.));
+        assert_eq!(evaluate("2 * -3"), Ok(-6.));
+        assert_eq!(evaluate("-2 ^ 2"), Ok(-4.));
+        assert_eq!(evaluate("(-2) ^ 2"), Ok(4.));
+    }
+
+    #[test]
+    fn numbers_and_constants() {
+        assert_eq!(evaluate("1e-3"), Ok(0.001));
+        assert_eq!(evaluate("2.5E+2"), Ok(250.));
+        assert_eq!(evaluate("1_000"), Ok(1000.));
+        assert_eq!(evaluate("2 * PI"), Ok(TAU));
+        assert_eq!(evaluate("sqrt(16) + cos(0)"), Ok(5.));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(evaluate(""), Err(ExpressionError::Empty));
+        assert_eq!(evaluate("   "), Err(ExpressionError::Empty));
+        assert_eq!(
+            evaluate("1 + $"),
+            Err(ExpressionError::UnexpectedCharacter('$', 4))
+        );
+        assert_eq!(
+            evaluate("1 + * 2"),
+            Err(ExpressionError::UnexpectedToken(4))
+        );
+        assert_eq!(evaluate("(1 + 2"), Err(ExpressionError::UnexpectedEnd));
+        assert_eq!(evaluate("1 2"), Err(ExpressionError::UnexpectedToken(2)));
+        assert_eq!(
+            evaluate("foo"),
+            Err(ExpressionError::UnknownIdentifier("foo".to_string()))
+        );
+        assert_eq!(
+            evaluate("1.2.3"),
+            Err(ExpressionError::InvalidNumber("1.2.3".to_string()))
+        );
+        assert_eq!(evaluate("1 / 0"), Err(ExpressionError::NotFinite));
+    }
+
+    #[test]
+    fn relative() {
+        assert_eq!(evaluate_relative("+=10", 5.), Ok(15.));
+        assert_eq!(evaluate_relative(" -= 1", 5.), Ok(4.));
+        assert_eq!(evaluate_relative("*=2+1", 5.), Ok(15.));
+        assert_eq!(evaluate_relative("/=2", 5.), Ok(2.5));
+        assert_eq!(evaluate_relative("^=2", 5.), Ok(25.));
+        assert_eq!(evaluate_relative("-1", 5.), Ok(-1.));
+        assert_eq!(
+            evaluate_relative("/=0", 5.),
+            Err(ExpressionError::NotFinite)
+        );
+        assert_eq!(
+            evaluate_relative(" +=1 $", 5.),
+            Err(ExpressionError::UnexpectedCharacter('$', 5))
+        );
+    }
+}
@@ -24,5 +24,9 @@ pub static GLOBAL_BORDER_COLOR: Lazy<Mutable<Color>> =
     Lazy::new(|| Mutable::new(DEFAULT_BORDER_COLOR));
 pub static GLOBAL_ERROR_COLOR: Lazy<Mutable<Color>> =
     Lazy::new(|| Mutable::new(DEFAULT_ERROR_COLOR));
+pub static GLOBAL_CHANGE_HIGHLIGHT_COLOR: Lazy<Mutable<Color>> =
+    Lazy::new(|| Mutable::new(DEFAULT_CHANGE_HIGHLIGHT_COLOR));
+pub static GLOBAL_CHANGE_HIGHLIGHT_DECAY: Lazy<Mutable<f32>> =
+    Lazy::new(|| Mutable::new(DEFAULT_CHANGE_HIGHLIGHT_DECAY));
 pub static GLOBAL_SCROLL_PIXELS: Lazy<Mutable<f32>> =
     Lazy::new(|| Mutable::new(DEFAULT_SCROLL_PIXELS));
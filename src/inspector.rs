@@ -5,13 +5,20 @@ use std::{
     collections::{HashMap, HashSet, VecDeque},
     convert::identity,
     fmt::{Debug, Display},
-    ops::{Deref, DerefMut, Not},
+    ops::{Deref, Not, RangeInclusive},
+    path::Path,
     str::FromStr,
-    sync::{Arc, Mutex, OnceLock, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
+        Arc, Mutex, OnceLock, RwLock,
+    },
 };
 
+use ::serde::de::DeserializeSeed;
 use bevy_app::prelude::*;
-use bevy_asset::{prelude::*, ReflectAsset, UntypedAssetId};
+use bevy_asset::{
+    prelude::*, LoadState, ReflectAsset, ReflectHandle, RenderAssetUsages, UntypedAssetId,
+};
 use bevy_color::{self, prelude::*};
 use bevy_core::prelude::*;
 use bevy_core_pipeline::prelude::*;
@@ -22,7 +29,13 @@ use bevy_cosmic_edit::{
 };
 use bevy_derive::*;
 use bevy_ecs::{
-    archetype::Archetypes, component::*, entity::Entities, prelude::*, system::*,
+    archetype::{ArchetypeEntity, ArchetypeGeneration, ArchetypeId, Archetypes},
+    component::*,
+    entity::Entities,
+    prelude::*,
+    query::QueryFilter,
+    schedule::{InternedScheduleLabel, NodeId, Stepping},
+    system::*,
     world::DeferredWorld,
 };
 use bevy_hierarchy::prelude::*;
@@ -31,17 +44,24 @@ use bevy_input::{mouse::MouseWheel, prelude::*};
 use bevy_log::prelude::*;
 use bevy_math::prelude::*;
 use bevy_picking::prelude::*;
-use bevy_reflect::{prelude::*, *};
+use bevy_reflect::{
+    attributes::CustomAttributes,
+    prelude::*,
+    serde::{
+        ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer,
+    },
+    *,
+};
 use bevy_render::{
     camera::{Camera, RenderTarget},
     prelude::*,
-    render_resource::{AsBindGroup, ShaderRef},
+    render_resource::{AsBindGroup, Extent3d, ShaderRef, TextureDimension, TextureFormat},
     view::RenderLayers,
 };
 use bevy_rich_text3d::{GlyphMeta, Text3d, Text3dPlugin, Text3dStyling, TextAtlas};
 use bevy_sprite::{prelude::*, AlphaMode2d, Material2d, Material2dPlugin};
 use bevy_text::{cosmic_text::Weight, *};
-use bevy_time::Time;
+use bevy_time::{Real, Time, TimeSystem, Virtual};
 use bevy_transform::prelude::*;
 use bevy_ui::prelude::*;
 use bevy_utils::prelude::*;
@@ -51,7 +71,7 @@ use haalka::{
     align::AlignabilityFacade,
     mouse_wheel_scrollable::{scroll_normalizer, ScrollDisabled},
     pointer_event_aware::UpdateHoverStatesDisabled,
-    prelude::*,
+    prelude::{futures_signals::signal_map::MutableBTreeMapLockMut, *},
     raw::{utils::remove_system_holder_on_remove, HaalkaObserver, HaalkaOneShotSystem},
     text_input::{FocusedTextInput, TextInputFocusOnDownDisabled},
     viewport_mutable::{LogicalRect, MutableViewport, Scene, Viewport},
@@ -63,7 +83,22 @@ use nucleo_matcher::{
 use num::Bounded;
 use strum::{Display, EnumIter, IntoEnumIterator};
 
-use super::{defaults::*, globals::*, reflect::*, style::*, utils::*, widgets::*};
+use super::{
+    attributes::FieldAttributes,
+    clipboard::Clipboard,
+    defaults::*,
+    expression,
+    globals::*,
+    reflect::*,
+    scene::{
+        entity_scene_name, export_entity, export_resources, import_scene, SceneFiles,
+        SCENE_EXTENSION,
+    },
+    search::{glob_match, type_path_matches, SearchQuery, SearchTerm},
+    style::*,
+    utils::*,
+    widgets::*,
+};
 use crate::{impl_syncers, signal_or};
 
 // TODO: implement frontend for at least all ui node types; how abt char, str, unit ? for unit, see (resources, Time, .context), should just be a tooltip
@@ -164,6 +199,9 @@ pub struct Inspector {
         Arc<Mutex<Vec<Box<dyn FnMut(ComponentsSignalVec) -> ComponentsSignalVec + Send>>>>,
     resources: MutableBTreeMap<ComponentId, FieldData>,
     assets: MutableBTreeMap<TypeId, AssetData>,
+    schedules: MutableBTreeMap<String, ScheduleData>,
+    events: MutableBTreeMap<ComponentId, EventsData>,
+    observers: MutableBTreeMap<Entity, EntityData>,
     search: Mutable<String>,
     first_target: Mutable<String>,
     second_target: Mutable<String>,
@@ -183,14 +221,84 @@ pub struct Inspector {
     highlighted_color: Mutable<Color>,
     unhighlighted_color: Mutable<Color>,
     border_color: Mutable<Color>,
+    change_highlight_color: Mutable<Color>,
+    change_highlight_decay: Mutable<f32>,
     scroll_pixels: Mutable<f32>,
     header: Mutable<Option<String>>,
     flatten_descendants: bool,
+    entity_query_filters: Vec<EntityQueryFilter>,
+    component_allowlist: Option<Vec<&'static str>>,
+    component_denylist: Vec<&'static str>,
+    watched: MutableVec<InspectionTarget>,
+    edit_settings: EditSettings,
+    time_controls: bool,
+}
+
+type EntityMatcher = Box<dyn FnMut(&mut World, Entity) -> bool + Send + Sync>;
+type EntityCollector = Box<dyn FnMut(&mut World) -> HashSet<Entity> + Send + Sync>;
+
+struct EntityQueryFilter {
+    // whether matching only depends on an entity's components, so only entities whose archetype changed need rechecking
+    archetypal: bool,
+    matches: EntityMatcher,
+    all: EntityCollector,
+}
+
+impl EntityQueryFilter {
+    fn new<F: QueryFilter + 'static>() -> Self {
+        let query_state: Arc<Mutex<Option<QueryState<Entity, F>>>> = default();
+        Self {
+            archetypal: F::IS_ARCHETYPAL,
+            matches: Box::new(clone!((query_state) move |world: &mut World, entity| {
+                query_state
+                    .lock()
+                    .unwrap()
+                    .get_or_insert_with(|| QueryState::new(world))
+                    .get(world, entity)
+                    .is_ok()
+            })),
+            all: Box::new(move |world: &mut World| {
+                query_state
+                    .lock()
+                    .unwrap()
+                    .get_or_insert_with(|| QueryState::new(world))
+                    .iter(world)
+                    .collect()
+            }),
+        }
+    }
+}
+
+#[derive(Default)]
+struct EntityQueryFiltersState {
+    filters: Vec<EntityQueryFilter>,
+    matched: HashSet<Entity>,
+    // the inspected entities' rows filter on these
+    rows: HashMap<Entity, Mutable<bool>>,
+    last_generation: Option<u64>,
+}
+
+// inspected entities must match all of the filters
+#[derive(Component, Clone)]
+struct EntityQueryFilters(Arc<Mutex<EntityQueryFiltersState>>);
+
+impl EntityQueryFilters {
+    fn row(&self, entity: Entity) -> Mutable<bool> {
+        let state = &mut *self.0.lock().unwrap();
+        state
+            .rows
+            .entry(entity)
+            .or_insert_with(|| Mutable::new(state.matched.contains(&entity)))
+            .clone()
+    }
 }
 
 #[derive(Component)]
 pub struct InspectorColumn;
 
+#[derive(Component)]
+struct InspectionTargetHolder;
+
 #[derive(Component)]
 pub struct ScrollbarHeight(f32);
 
@@ -699,6 +807,42 @@ fn atom_score(matcher: &mut Matcher, atom: &Pattern, name: &str) -> Option<u32>
     atom.score(nucleo_matcher::Utf32String::from(name).slice(..), matcher)
 }
 
+// matches names against the fuzzy and `name:` terms of a search query
+struct NameMatcher {
+    matcher: Matcher,
+    atom_option: Option<Pattern>,
+    globs: Vec<String>,
+}
+
+impl NameMatcher {
+    fn new(query: &SearchQuery) -> Self {
+        let fuzzy = query.fuzzy();
+        let (matcher, atom) = make_matcher_and_atom(&fuzzy);
+        Self {
+            matcher,
+            atom_option: fuzzy.is_empty().not().then_some(atom),
+            globs: query.name_globs().map(str::to_string).collect(),
+        }
+    }
+
+    fn matches(&mut self, name_option: Option<&str>) -> bool {
+        if self.atom_option.is_none() && self.globs.is_empty() {
+            return true;
+        }
+        let Some(name) = name_option else {
+            return false;
+        };
+        self.atom_option
+            .as_ref()
+            .is_none_or(|atom| atom_score(&mut self.matcher, atom, name).is_some())
+            && self
+                .globs
+                .as_slice()
+                .iter()
+                .all(|glob| glob_match(glob, name))
+    }
+}
+
 fn make_target(
     root: InspectionTargetRoot,
     first_target: &str,
@@ -759,6 +903,9 @@ impl ElementWrapper for Inspector {
             components_transformers,
             resources,
             assets,
+            schedules,
+            events,
+            observers,
             search,
             first_target,
             second_target,
@@ -779,8 +926,43 @@ impl ElementWrapper for Inspector {
             unhighlighted_color,
             header,
             flatten_descendants,
+            entity_query_filters,
+            component_allowlist,
+            component_denylist,
+            watched,
+            edit_settings,
+            time_controls,
             ..
         } = self;
+        if component_allowlist.is_some() || !component_denylist.is_empty() {
+            components_transformers.lock().unwrap().push(Box::new(
+                move |components: ComponentsSignalVec| {
+                    let (component_allowlist, component_denylist) =
+                        (component_allowlist.clone(), component_denylist.clone());
+                    components
+                        .filter(move |(_, FieldData { name, .. })| {
+                            component_allowlist
+                                .as_ref()
+                                .is_none_or(|allowlist| allowlist.contains(&name.as_str()))
+                                && !component_denylist.contains(&name.as_str())
+                        })
+                        .boxed()
+                },
+            ));
+        }
+        let entity_query_filters = (!entity_query_filters.is_empty()).then(|| {
+            EntityQueryFilters(Arc::new(Mutex::new(EntityQueryFiltersState {
+                filters: entity_query_filters,
+                ..default()
+            })))
+        });
+        if let Some(entity_query_filters) = &entity_query_filters {
+            entities_transformers.lock().unwrap().push(Box::new(clone!((entity_query_filters) move |entities: EntitySignalVec| {
+                entities
+                    .filter_signal_cloned(clone!((entity_query_filters) move |&(entity, _)| entity_query_filters.row(entity).signal()))
+                    .boxed()
+            })));
+        }
         let flatten_descendants = Mutable::new(flatten_descendants);
         let viewport_height = Mutable::new(0.);
         let inspector_hovered = Mutable::new(false);
@@ -794,6 +976,7 @@ impl ElementWrapper for Inspector {
         let collapsed = Mutable::new(false);
         let tooltip = Mutable::new(None);
         let search_target_root = Mutable::new(InspectionTargetRoot::Entity);
+        let value_matches = MutableVec::new();
         let search_target_root_focused = Mutable::new(false);
         let targeting_target_root = Mutable::new(InspectionTargetRoot::Entity);
         let targeting_target_root_focused = Mutable::new(false);
@@ -822,46 +1005,40 @@ impl ElementWrapper for Inspector {
                             InspectionTargetRoot::Entity => {
                                 unfilter_resources();
                                 unfilter_assets();
-                                if search.is_empty() {
+                                let query = SearchQuery::parse(search);
+                                if query.is_empty() {
                                     unfilter_entities();
-                                } else {
-                                    let (mut matcher, atom) = make_matcher_and_atom(search);
+                                // structural queries are evaluated against archetypes by `filter_entities_by_search`
+                                } else if !query.is_structural() {
+                                    let mut name_matcher = NameMatcher::new(&query);
                                     for (_, EntityData { name: name_option, filtered, .. }) in entities.lock_ref().iter() {
-                                        filtered.set_neq(
-                                            if let Some(name) = &*name_option.lock_ref() {
-                                                atom_score(&mut matcher, &atom, name).is_none()
-                                            } else {
-                                                true
-                                            }
-                                        )
+                                        filtered.set_neq(!name_matcher.matches(name_option.lock_ref().as_deref()))
                                     }
                                 }
                             },
                             InspectionTargetRoot::Resource => {
                                 unfilter_assets();
                                 unfilter_entities();
-                                if search.is_empty() {
+                                let query = SearchQuery::parse(search);
+                                if query.is_empty() {
                                     unfilter_resources();
                                 } else {
-                                    let (mut matcher, atom) = make_matcher_and_atom(search);
+                                    let mut name_matcher = NameMatcher::new(&query);
                                     for (_, FieldData { name, filtered, .. }) in resources.lock_ref().iter() {
-                                        filtered.set_neq(
-                                            atom_score(&mut matcher, &atom, name).is_none()
-                                        )
+                                        filtered.set_neq(!name_matcher.matches(Some(name)))
                                     }
                                 }
                             },
                             InspectionTargetRoot::Asset => {
                                 unfilter_entities();
                                 unfilter_resources();
-                                if search.is_empty() {
+                                let query = SearchQuery::parse(search);
+                                if query.is_empty() {
                                     unfilter_assets();
                                 } else {
-                                    let (mut matcher, atom) = make_matcher_and_atom(search);
+                                    let mut name_matcher = NameMatcher::new(&query);
                                     for (_, AssetData { name, filtered, .. }) in assets.lock_ref().iter() {
-                                        filtered.set_neq(
-                                            atom_score(&mut matcher, &atom, name).is_none()
-                                        )
+                                        filtered.set_neq(!name_matcher.matches(Some(name)))
                                     }
                                 }
                             },
@@ -882,20 +1059,15 @@ impl ElementWrapper for Inspector {
                 let &show = show_search.signal(),
                 let root = search_target_root.signal(),
                 let search = search.signal_cloned() => {
-                    if show && search.is_empty().not() {
-                        let search = search.clone();
+                    let query = SearchQuery::parse(search);
+                    if show && query.is_empty().not() {
                         let task = match root {
                             InspectionTargetRoot::Entity => {
                                 entities.signal_map_cloned().for_each(move |map_diff| {
                                     if let MapDiff::Insert { value: EntityData { name: name_option, filtered, .. }, .. } = map_diff {
-                                        let (mut matcher, atom) = make_matcher_and_atom(&search);
-                                        filtered.set_neq(
-                                            if let Some(name) = &*name_option.lock_ref() {
-                                                atom_score(&mut matcher, &atom, name).is_none()
-                                            } else {
-                                                true
-                                            }
-                                        )
+                                        if !query.is_structural() {
+                                            filtered.set_neq(!NameMatcher::new(&query).matches(name_option.lock_ref().as_deref()))
+                                        }
                                     }
                                     async {}
                                 })
@@ -904,10 +1076,7 @@ impl ElementWrapper for Inspector {
                             InspectionTargetRoot::Resource => {
                                 resources.signal_map_cloned().for_each(move |map_diff| {
                                     if let MapDiff::Insert { value: FieldData { name, filtered, .. }, .. } = map_diff {
-                                        let (mut matcher, atom) = make_matcher_and_atom(&search);
-                                        filtered.set_neq(
-                                            atom_score(&mut matcher, &atom, &name).is_none()
-                                        )
+                                        filtered.set_neq(!NameMatcher::new(&query).matches(Some(&name)))
                                     }
                                     async {}
                                 })
@@ -916,10 +1085,7 @@ impl ElementWrapper for Inspector {
                             InspectionTargetRoot::Asset => {
                                 assets.signal_map_cloned().for_each(move |map_diff| {
                                     if let MapDiff::Insert { value: AssetData { name, filtered, .. }, .. } = map_diff {
-                                        let (mut matcher, atom) = make_matcher_and_atom(&search);
-                                        filtered.set_neq(
-                                            atom_score(&mut matcher, &atom, name).is_none()
-                                        )
+                                        filtered.set_neq(!NameMatcher::new(&query).matches(Some(name)))
                                     }
                                     async {}
                                 })
@@ -962,9 +1128,45 @@ impl ElementWrapper for Inspector {
         .width(Val::Percent(100.))
         .height(Val::Percent(100.))
         .hovered_sync(inspector_hovered.clone())
-        .update_raw_el(clone!((show_search, show_targeting, first_target, second_target, third_target, search_target_root, targeting_target_root, search) move |raw_el| {
+        .update_raw_el(clone!((show_search, show_targeting, first_target, second_target, third_target, search_target_root, targeting_target_root, search, entities, resources, assets, value_matches, watched) move |raw_el| {
             raw_el
+            .insert(InspectionTargetHolder)
+            .insert(WatchList(watched.clone()))
+            .insert(edit_settings)
+            .apply(|raw_el| if let Some(entity_query_filters) = entity_query_filters { raw_el.insert(entity_query_filters) } else { raw_el })
             .hold_tasks([search_task, on_insert_search_filterer_task])
+            .component_signal::<ValueSearch, _>(
+                clone!((search_target_root, search, entities, resources, assets, value_matches) map_ref! {
+                    let &show = show_search.signal(),
+                    let &root = search_target_root.signal(),
+                    let value = search.signal_ref(|search| SearchQuery::parse(search).value().map(str::to_string)) => {
+                        match value.clone().filter(|_| show) {
+                            Some(value) => Some(ValueSearch {
+                                value,
+                                root,
+                                entities: entities.clone(),
+                                resources: resources.clone(),
+                                assets: assets.clone(),
+                                matches: value_matches.clone(),
+                            }),
+                            None => {
+                                value_matches.lock_mut().clear();
+                                None
+                            }
+                        }
+                    }
+                })
+            )
+            .component_signal::<EntitySearch, _>(
+                clone!((search_target_root, search) map_ref! {
+                    let &show = show_search.signal(),
+                    let &root = search_target_root.signal(),
+                    let query = search.signal_ref(|search| SearchQuery::parse(search)) => {
+                        (show && matches!(root, InspectionTargetRoot::Entity) && query.is_structural())
+                            .then(|| EntitySearch { query: query.clone(), entities: entities.clone() })
+                    }
+                })
+            )
             .on_signal_with_system(
                 clone!((search, search_target_root, targeting_target_root) map_ref! {
                     let &show_search = show_search.signal(),
@@ -1160,7 +1362,7 @@ impl ElementWrapper for Inspector {
         })
         // header
         .item(
-            El::<Node>::new()
+            Row::<Node>::new()
             .update_raw_el(|raw_el| {
                 raw_el
                 .insert(PickingBehavior::default())
@@ -1251,7 +1453,8 @@ impl ElementWrapper for Inspector {
                 }))
             })
             .apply(background_style(primary_background_color.signal()))
-            .child(
+            .item(time_controls.then(time_controls_bar))
+            .item(
                 El::<Node>::new()
                 .align(Align::new().right())
                 .apply(padding_style(BoxEdge::ALL, row_gap.signal()))
@@ -1392,6 +1595,38 @@ impl ElementWrapper for Inspector {
                             }
                         }
                     })
+                    .item(
+                        Column::<Node>::new()
+                        .width(Val::Percent(100.))
+                        .items_signal_vec(watched.signal_vec_cloned().map(clone!((font_size, row_gap, column_gap, padding, border_width, border_color, tertiary_background_color, highlighted_color, unhighlighted_color) move |target| {
+                            let resolved = Mutable::new(None);
+                            Column::<Node>::new()
+                            .width(Val::Percent(100.))
+                            .update_raw_el(clone!((target, resolved) move |raw_el| {
+                                raw_el
+                                .on_spawn(clone!((target, resolved) move |world, _| resolved.set(resolve_inspection_target(world, &target))))
+                                .insert(WatchedTarget { target, resolved })
+                            }))
+                            .item(
+                                El::<Node>::new()
+                                .apply(padding_style(BoxEdge::HORIZONTAL, padding.signal()))
+                                .child(inspection_target_link(target.clone(), target.to_string(), font_size.clone(), highlighted_color.clone(), unhighlighted_color.clone()))
+                            )
+                            .item_signal(resolved.signal_cloned().map_some(clone!((row_gap, column_gap, padding, border_width, border_color, tertiary_background_color, highlighted_color, unhighlighted_color) move |(input, type_path, path)| {
+                                watched_field(input, type_path, path, |field_element| {
+                                    field_element
+                                    .row_gap_signal(row_gap.signal())
+                                    .column_gap_signal(column_gap.signal())
+                                    .type_path_color_signal(tertiary_background_color.signal())
+                                    .border_width_signal(border_width.signal())
+                                    .border_color_signal(border_color.signal())
+                                    .padding_signal(padding.signal())
+                                    .highlighted_color_signal(highlighted_color.signal())
+                                    .unhighlighted_color_signal(unhighlighted_color.signal())
+                                })
+                            })))
+                        })))
+                    )
                     .item({
                         let hovered = Mutable::new(false);
                         let pinned = Mutable::new(false);
@@ -1408,7 +1643,8 @@ impl ElementWrapper for Inspector {
                             secondary_background_color.clone(),
                             padding.clone(),
                             pinned,
-                            expanded.clone()
+                            expanded.clone(),
+                            None
                         )
                         .update_raw_el(move |raw_el| {
                             raw_el
@@ -1422,61 +1658,56 @@ impl ElementWrapper for Inspector {
                                 .width(Val::Percent(100.))
                                 .apply(move_style(Move_::Right, padding.signal()))
                                 .apply(left_bordered_style(border_width.signal(), map_bool_signal(hovered.signal(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
-                                .items_signal_vec({
-                                    let mut signal_vec = entities.entries_cloned().boxed();
-                                    signal_vec = signal_vec
-                                        .map(clone!((components_transformers) move |mut data| {
-                                            data.1.components_transformers = components_transformers.clone();
-                                            data
-                                        }))
-                                        .boxed();
-                                    for f in entities_transformers.lock().unwrap().iter_mut() {
-                                        signal_vec = f(signal_vec);
-                                    }
-                                    signal_vec
-                                        .filter_signal_cloned(|(_, EntityData { filtered, .. })| {
-                                            signal::not(filtered.signal())
-                                        })
-                                        .map(clone!(
-                                            (
-                                                font_size,
-                                                row_gap,
-                                                column_gap,
-                                                primary_background_color,
-                                                secondary_background_color,
-                                                border_color,
-                                                border_width,
-                                                padding,
-                                                highlighted_color,
-                                                unhighlighted_color
-                                            ) move |(id, data)| {
-                                            MultiFieldElement::new(MultiFieldData::Entity { id, data })
-                                            .update_raw_el(|raw_el| {
-                                                raw_el
-                                                .on_spawn_with_system(|In(entity), childrens: Query<&Children>, mut commands: Commands| {
-                                                    // TODO: use relations to safely fetch the header
-                                                    if let Some(&child) = i_born(entity, &childrens, 0) {
-                                                        if let Some(mut entity) = commands.get_entity(child) {
-                                                            entity.try_insert(GlobalZIndex(z_order("header")));
-                                                        }
+                                .apply(virtualized_items(
+                                    {
+                                        let mut signal_vec = entities.entries_cloned().boxed();
+                                        signal_vec = signal_vec
+                                            .map(clone!((components_transformers) move |mut data| {
+                                                data.1.components_transformers = components_transformers.clone();
+                                                data
+                                            }))
+                                            .boxed();
+                                        for f in entities_transformers.lock().unwrap().iter_mut() {
+                                            signal_vec = f(signal_vec);
+                                        }
+                                        signal_vec
+                                            .filter_signal_cloned(|(_, EntityData { filtered, .. })| {
+                                                signal::not(filtered.signal())
+                                            })
+                                    },
+                                    |(entity, EntityData { name, .. }), part| {
+                                        let ProgressPart::Field(target) = part else { return false };
+                                        let target = target.to_lowercase();
+                                        target == entity.to_string() || Some(target) == name.get_cloned().map(|name| name.to_lowercase())
+                                    },
+                                    clone!((font_size, row_gap, column_gap, primary_background_color, secondary_background_color, border_color, border_width, padding, highlighted_color, unhighlighted_color) move |(id, data)| {
+                                        MultiFieldElement::new(MultiFieldData::Entity { id, data })
+                                        .update_raw_el(|raw_el| {
+                                            raw_el
+                                            .on_spawn_with_system(|In(entity), childrens: Query<&Children>, mut commands: Commands| {
+                                                // TODO: use relations to safely fetch the header
+                                                if let Some(&child) = i_born(entity, &childrens, 0) {
+                                                    if let Some(mut entity) = commands.get_entity(child) {
+                                                        entity.try_insert(GlobalZIndex(z_order("header")));
                                                     }
-                                                })
+                                                }
                                             })
-                                            .show_name()
-                                            .font_size_signal(font_size.signal())
-                                            .row_gap_signal(row_gap.signal())
-                                            .column_gap_signal(column_gap.signal())
-                                            .primary_background_color_signal(primary_background_color.signal())
-                                            .secondary_background_color_signal(secondary_background_color.signal())
-                                            .border_color_signal(border_color.signal())
-                                            .border_width_signal(border_width.signal())
-                                            .padding_signal(padding.signal())
-                                            .highlighted_color_signal(highlighted_color.signal())
-                                            .unhighlighted_color_signal(unhighlighted_color.signal())
-                                            .into_el()
-                                            .width(Val::Percent(100.))
-                                        }))
-                                })
+                                        })
+                                        .show_name()
+                                        .font_size_signal(font_size.signal())
+                                        .row_gap_signal(row_gap.signal())
+                                        .column_gap_signal(column_gap.signal())
+                                        .primary_background_color_signal(primary_background_color.signal())
+                                        .secondary_background_color_signal(secondary_background_color.signal())
+                                        .border_color_signal(border_color.signal())
+                                        .border_width_signal(border_width.signal())
+                                        .padding_signal(padding.signal())
+                                        .highlighted_color_signal(highlighted_color.signal())
+                                        .unhighlighted_color_signal(unhighlighted_color.signal())
+                                        .into_el()
+                                        .width(Val::Percent(100.))
+                                    })
+                                ))
                             }))
                         )
                     })
@@ -1496,13 +1727,16 @@ impl ElementWrapper for Inspector {
                             secondary_background_color.clone(),
                             padding.clone(),
                             pinned,
-                            expanded.clone()
+                            expanded.clone(),
+                            Some(HotkeyTarget::Resources(resources.clone()))
                         )
-                        .update_raw_el(|raw_el| {
+                        .update_raw_el(clone!((resources, hovered) move |raw_el| {
                             raw_el
                             .insert(SyncResources)
                             .insert(ResourcesHeader)
-                        })
+                            .insert(HotkeyTarget::Resources(resources))
+                            .component_signal::<Hovered, _>(hovered.signal().map_true(default))
+                        }))
                         .item_signal(
                             expanded.signal().dedupe().map_true(clone!((padding, border_width, hovered, tertiary_background_color, border_color, highlighted_color, unhighlighted_color, row_gap, column_gap) move || {
                                 Column::<Node>::new()
@@ -1545,7 +1779,8 @@ impl ElementWrapper for Inspector {
                             secondary_background_color.clone(),
                             padding.clone(),
                             pinned,
-                            expanded.clone()
+                            expanded.clone(),
+                            None
                         )
                         .update_raw_el(|raw_el| {
                             raw_el
@@ -1605,6 +1840,10 @@ impl ElementWrapper for Inspector {
                             }))
                         )
                     })
+                    .item(events_root(events))
+                    .item(observers_root(observers))
+                    .item(schedules_root(schedules))
+                    .item(ecs_stats_root())
                     .item(
                         // TODO: doesn't work without a wrapper for some reason
                         El::<Node>::new()
@@ -1769,7 +2008,8 @@ impl ElementWrapper for Inspector {
                         search_focused,
                         column_gap,
                         search_target_root,
-                        search_target_root_focused
+                        search_target_root_focused,
+                        value_matches
                     ) move || {
                         let hovered = Mutable::new(false);
                         Column::<Node>::new()
@@ -1780,6 +2020,10 @@ impl ElementWrapper for Inspector {
                         .apply(border_radius_style(BoxCorner::TOP, border_radius.signal()))
                         .apply(border_width_style([BoxEdge::Top], border_width.signal()))
                         .apply(border_color_style(border_color.signal()))
+                        .items_signal_vec(value_matches.signal_vec_cloned().map(clone!((font_size, highlighted_color, unhighlighted_color) move |ValueMatch { target, value }| {
+                            let text = format!("{target} = {value}");
+                            inspection_target_link(target, text, font_size.clone(), highlighted_color.clone(), unhighlighted_color.clone())
+                        })))
                         .item(
                             El::<Node>::new()
                             .apply(left_bordered_style(border_width.signal(), map_bool_signal(signal_or!(hovered.signal(), search_focused.signal()).dedupe(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
@@ -2016,8 +2260,9 @@ impl ElementWrapper for Inspector {
             Some(self.wrapper_stack),
         ))
         .layer_signal(
-            tooltip.signal_cloned().dedupe_cloned().map_some(clone!((primary_background_color, font_size, padding, border_width) move |TooltipData { text, .. }| {
-                El::<Node>::new()
+            tooltip.signal_cloned().dedupe_cloned().map_some(clone!((primary_background_color, font_size, padding, border_width, row_gap) move |TooltipData { text, image, .. }| {
+                let image_height = image.as_ref().map(|(_, size)| size.y).unwrap_or_default();
+                Column::<Node>::new()
                 .update_raw_el(clone!((font_size, padding, border_width) move |raw_el| {
                     raw_el
                     .insert(Tooltip)
@@ -2033,7 +2278,7 @@ impl ElementWrapper for Inspector {
                         if let Some(inspector) = inspector_ancestor.get(entity) {
                             if let Ok(&TooltipTargetPosition(position)) = tooltip_target_position.get(inspector) {
                                 // TODO: more intelligent way to get this height? waiting for node to reach "full size" is pretty cringe
-                                let expected_tooltip_height = font_size.get() + padding.get() + border_width.get() * 2. + 3.;  // TODO: where did this 3. come from ?
+                                let expected_tooltip_height = font_size.get() + padding.get() + border_width.get() * 2. + 3. + image_height;  // TODO: where did this 3. come from ?
                                 move_tooltip_to_position.move_(entity, position, Some(expected_tooltip_height));
                                 if let Some(mut entity) = commands.get_entity(entity) {
                                     entity.try_insert(Visibility::Inherited);
@@ -2056,13 +2301,20 @@ impl ElementWrapper for Inspector {
                 .apply(border_color_style(border_color.signal()))
                 .apply(border_radius_style(BoxCorner::ALL, border_radius.signal()))
                 .apply(background_style(primary_background_color.signal()))
-                .child({
+                .apply(column_style(row_gap.signal()))
+                .item(text.is_empty().not().then(|| {
                     El::<Text>::new()
                     .text_font(TextFont::from_font_size(font_size.get()))
                     .text_font_signal(font_size.signal().map(TextFont::from_font_size))
                     .text(Text(text))
                     .apply(text_no_wrap)
-                })
+                }))
+                .item(image.map(|(image, size)| {
+                    El::<ImageNode>::new()
+                    .image_node(ImageNode::new(image))
+                    .width(Val::Px(size.x))
+                    .height(Val::Px(size.y))
+                }))
             }))
         )
         // TODO: move these to the inspector once the resize border wrapper is no longer needed
@@ -2187,6 +2439,9 @@ impl Inspector {
             components_transformers: Arc::new(Mutex::new(vec![])),
             resources: MutableBTreeMap::new(),
             assets: MutableBTreeMap::new(),
+            schedules: MutableBTreeMap::new(),
+            events: MutableBTreeMap::new(),
+            observers: MutableBTreeMap::new(),
             search: Mutable::new(String::new()),
             first_target: Mutable::new(String::new()),
             second_target: Mutable::new(String::new()),
@@ -2205,9 +2460,17 @@ impl Inspector {
             highlighted_color: GLOBAL_HIGHLIGHTED_COLOR.clone(),
             unhighlighted_color: GLOBAL_UNHIGHLIGHTED_COLOR.clone(),
             border_color: GLOBAL_BORDER_COLOR.clone(),
+            change_highlight_color: GLOBAL_CHANGE_HIGHLIGHT_COLOR.clone(),
+            change_highlight_decay: GLOBAL_CHANGE_HIGHLIGHT_DECAY.clone(),
             scroll_pixels: GLOBAL_SCROLL_PIXELS.clone(),
             header: Mutable::new(None),
             flatten_descendants: false,
+            entity_query_filters: vec![],
+            component_allowlist: None,
+            component_denylist: vec![],
+            watched: MutableVec::new(),
+            edit_settings: EditSettings::default(),
+            time_controls: false,
         }
     }
 
@@ -2226,6 +2489,21 @@ impl Inspector {
         self
     }
 
+    pub fn schedules(mut self, mut schedules: MutableBTreeMap<String, ScheduleData>) -> Self {
+        std::mem::swap(&mut self.schedules, &mut schedules);
+        self
+    }
+
+    pub fn events(mut self, mut events: MutableBTreeMap<ComponentId, EventsData>) -> Self {
+        std::mem::swap(&mut self.events, &mut events);
+        self
+    }
+
+    pub fn observers(mut self, mut observers: MutableBTreeMap<Entity, EntityData>) -> Self {
+        std::mem::swap(&mut self.observers, &mut observers);
+        self
+    }
+
     pub fn with_entities(
         self,
         f: impl FnMut(EntitySignalVec) -> EntitySignalVec + Send + 'static,
@@ -2265,6 +2543,37 @@ impl Inspector {
         })
     }
 
+    /// Only inspect entities matching the [`QueryFilter`] `F`, e.g. `(With<Enemy>, Without<Health>)`.
+    pub fn query_filter<F: QueryFilter + 'static>(mut self) -> Self {
+        self.entity_query_filters
+            .push(EntityQueryFilter::new::<F>());
+        self
+    }
+
+    /// Only inspect entities with the component `T`.
+    pub fn with_component<T: Component>(self) -> Self {
+        self.query_filter::<With<T>>()
+    }
+
+    /// Only inspect entities without the component `T`.
+    pub fn without_component<T: Component>(self) -> Self {
+        self.query_filter::<Without<T>>()
+    }
+
+    /// Only show the components added to the allowlist.
+    pub fn allow_component<T: Component>(mut self) -> Self {
+        self.component_allowlist
+            .get_or_insert_default()
+            .push(std::any::type_name::<T>());
+        self
+    }
+
+    /// Don't show the component `T`.
+    pub fn deny_component<T: Component>(mut self) -> Self {
+        self.component_denylist.push(std::any::type_name::<T>());
+        self
+    }
+
     pub fn with_components(
         self,
         f: impl FnMut(ComponentsSignalVec) -> ComponentsSignalVec + Send + 'static,
@@ -2286,6 +2595,30 @@ impl Inspector {
         self
     }
 
+    /// Show the field at `target` in the watch section at the top of the inspector.
+    pub fn watch(self, target: impl Into<InspectionTarget>) -> Self {
+        self.watched.lock_mut().push_cloned(target.into());
+        self
+    }
+
+    /// Don't write edits equal to the field's current value (per `reflect_partial_eq`), so they don't mark components changed.
+    pub fn skip_unchanged_edits(mut self) -> Self {
+        self.edit_settings.skip_unchanged = true;
+        self
+    }
+
+    /// Write edits to components and resources without triggering change detection.
+    pub fn silent_edits(mut self) -> Self {
+        self.edit_settings.silent = true;
+        self
+    }
+
+    /// Show pause, step, and relative speed controls for `Time<Virtual>` in the header.
+    pub fn time_controls(mut self) -> Self {
+        self.time_controls = true;
+        self
+    }
+
     pub fn flatten_descendants(mut self) -> Self {
         self.flatten_descendants = true;
         self
@@ -2306,6 +2639,8 @@ impl Inspector {
         highlighted_color: Color,
         unhighlighted_color: Color,
         border_color: Color,
+        change_highlight_color: Color,
+        change_highlight_decay: f32,
         scroll_pixels: f32,
         header: Option<String>,
     }
@@ -2553,16 +2888,26 @@ fn text_no_wrap<E: Element>(el: E) -> E {
     })
 }
 
+const OBSERVED_BY_TYPE_PATH: &str = "bevy_ecs::observer::entity_observer::ObservedBy";
+
 fn entity_header(
     entity: Entity,
     name: Mutable<Option<String>>,
+    components: MutableBTreeMap<ComponentId, FieldData>,
     hovered: Mutable<bool>,
     font_size: Mutable<f32>,
     highlighted_color: Mutable<Color>,
     unhighlighted_color: Mutable<Color>,
 ) -> impl Element + PointerEventAware {
     let guessed_name = Mutable::new("Entity".to_string());
-    HighlightableText::new()
+    // bevy 0.15 doesn't expose which observers watch an entity or what they listen to, only that it's watched
+    let observed = components
+        .entries_cloned()
+        .filter(|(_, FieldData { name, .. })| name == OBSERVED_BY_TYPE_PATH)
+        .is_empty()
+        .map(Not::not)
+        .dedupe();
+    let name_text = HighlightableText::new()
     .highlighted_signal(hovered.signal())
     .with_text(clone!((font_size, guessed_name) move |text| {
         text
@@ -2604,25 +2949,151 @@ fn entity_header(
             }
         }
     }))
+;
+    Row::<Node>::new()
+        .apply(row_style(GLOBAL_COLUMN_GAP.signal()))
+        .item(name_text)
+        .item_signal(
+            hovered
+                .signal()
+                .map_true(move || scene_actions(HotkeyTarget::Entity(entity))),
+        )
+        .item_signal(observed.map_true(|| {
+            DynamicText::new()
+                .text("observed".to_string())
+                .color_signal(GLOBAL_TERTIARY_BACKGROUND_COLOR.signal())
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                .update_raw_el(|raw_el| {
+                    raw_el
+                        .insert(PickingBehavior::default())
+                        // jump to the observers section
+                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                            |In((entity, click)): In<(Entity, Pointer<Click>)>,
+                             inspector_column_ancestor: InspectorColumnAncestor,
+                             childrens: Query<&Children>,
+                             observers_roots: Query<&HeaderData, With<SyncObservers>>,
+                             mut commands: Commands| {
+                                if !matches!(click.button, PointerButton::Primary) {
+                                    return;
+                                }
+                                let Some(inspector_column) = inspector_column_ancestor.get(entity)
+                                else {
+                                    return;
+                                };
+                                let Some((observers_root, HeaderData { expanded, .. })) = childrens
+                                    .iter_descendants(inspector_column)
+                                    .find_map(|descendant| {
+                                        observers_roots
+                                            .get(descendant)
+                                            .ok()
+                                            .map(|data| (descendant, data))
+                                    })
+                                else {
+                                    return;
+                                };
+                                expanded.set_neq(true);
+                                if let Some(mut entity) = commands.get_entity(observers_root) {
+                                    entity.try_insert(WaitForBirth { ceiling: None });
+                                }
+                            },
+                        )
+                })
+                .apply(text_no_wrap)
+        }))
+}
+
+// clickable text next to a header's name
+fn header_action(
+    text: &'static str,
+    action: impl Fn(&mut World, bool) -> Result<(), String> + Send + Sync + Clone + 'static,
+) -> DynamicText {
+    DynamicText::new()
+        .text(text.to_string())
+        .color_signal(GLOBAL_TERTIARY_BACKGROUND_COLOR.signal())
+        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+        .update_raw_el(|raw_el| {
+            raw_el
+                .insert(PickingBehavior::default())
+                .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                    move |In((_, click)): In<(Entity, Pointer<Click>)>,
+                          keys: Res<ButtonInput<KeyCode>>,
+                          mut commands: Commands| {
+                        if !matches!(click.button, PointerButton::Primary) {
+                            return;
+                        }
+                        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+                        commands.queue(clone!((action) move |world: &mut World| {
+                            if let Err(e) = action(world, shift) {
+                                warn!("{e}");
+                            }
+                        }));
+                    },
+                )
+        })
+        .apply(text_no_wrap)
+}
+
+// export and import actions for a header's scene file, shift click exports entities along with their descendants
+fn scene_actions(target: HotkeyTarget) -> Row<Node> {
+    Row::<Node>::new()
+        .apply(row_style(GLOBAL_COLUMN_GAP.signal()))
+        .item(header_action(
+            "export",
+            clone!((target) move |world, shift| {
+                export_hotkey_target(world, target.clone(), shift)
+            }),
+        ))
+        .item(header_action("import", move |world, _| {
+            import_hotkey_target(world, Some(target.clone()))
+        }))
 }
 
 pub fn apply_to_accessory_target(
     world: &mut World,
     target: AccessoryTarget,
     f: impl FnOnce(&mut dyn Reflect),
+) {
+    edit_accessory_target(world, target, false, f)
+}
+
+// silent edits don't trigger change detection, assets are always marked modified
+pub fn edit_accessory_target(
+    world: &mut World,
+    target: AccessoryTarget,
+    silent: bool,
+    f: impl FnOnce(&mut dyn Reflect),
 ) {
     match target {
-        AccessoryTarget::Component { owner, component } => match owner {
-            ComponentOwnerType::Entity(entity) => {
-                with_reflect_component_mut(world, entity, component, f);
-            }
-            ComponentOwnerType::Resource => {
-                with_reflect_resource_mut(world, component, f);
+        AccessoryTarget::Component { owner, component } => {
+            let applied = match (owner, silent) {
+                (ComponentOwnerType::Entity(entity), false) => {
+                    with_reflect_component_mut(world, entity, component, f)
+                }
+                (ComponentOwnerType::Entity(entity), true) => {
+                    with_reflect_component_mut_silent(world, entity, component, f)
+                }
+                (ComponentOwnerType::Resource, false) => {
+                    with_reflect_resource_mut(world, component, f)
+                }
+                (ComponentOwnerType::Resource, true) => {
+                    with_reflect_resource_mut_silent(world, component, f)
+                }
+            };
+            if applied.is_some() && !silent {
+                let tick = world.change_tick();
+                if let Some(mut edits) = world.get_resource_mut::<InspectorEdits>() {
+                    edits.0.insert((owner, component), tick);
+                }
             }
-        },
+        }
         AccessoryTarget::Asset { asset, handle } => {
             with_reflect_asset_mut(world, asset, handle, f);
         }
+        AccessoryTarget::EventDraft { events } => {
+            if let Some(draft) = world.resource_mut::<EventDrafts>().get_mut(&events) {
+                f(draft.as_mut());
+            }
+        }
     }
 }
 
@@ -2717,10 +3188,12 @@ impl ElementWrapper for MultiFieldElement {
             } => expanded.clone(),
         };
         el
-        .update_raw_el(clone!((data, pinned, expanded) move |mut raw_el| {
+        .update_raw_el(clone!((data, pinned, expanded, hovered) move |mut raw_el| {
+            raw_el = raw_el.component_signal::<Hovered, _>(hovered.signal().map_true(default));
             raw_el = match &data {
                 MultiFieldData::Entity { id: entity, data: EntityData { name, components, .. } } => {
                     raw_el
+                    .insert(HotkeyTarget::Entity(*entity))
                     .insert(EntityRoot { entity: *entity, components: HashSet::from_iter(components.lock_ref().iter().map(|(&id, _)| id)), name: name.clone() })
                 },
                 MultiFieldData::Asset { id: asset, data: AssetData { name, handles, .. } } => {
@@ -2832,11 +3305,12 @@ impl ElementWrapper for MultiFieldElement {
         .hovered_sync(hovered.clone())
         .item(if show_name {
             match &data {
-                MultiFieldData::Entity { id: entity, data: EntityData { name, .. } } => {
+                MultiFieldData::Entity { id: entity, data: EntityData { name, components, .. } } => {
                     let entity = *entity;
                     entity_header(
                         entity,
                         name.clone(),
+                        components.clone(),
                         hovered.clone(),
                         font_size,
                         highlighted_color.clone(),
@@ -2846,7 +3320,7 @@ impl ElementWrapper for MultiFieldElement {
                 },
                 MultiFieldData::Asset { data: AssetData { name, .. }, .. } => {
                     field_header(
-                        name.split("::").last().unwrap_or_default().to_string(),
+                        Mutable::new(name.split("::").last().unwrap_or_default().to_string()),
                         Some(FieldType::Field(name.to_string())),
                         Mutable::new(Some(name.to_string())),
                         Mutable::new(Viewability::Viewable),
@@ -2863,13 +3337,27 @@ impl ElementWrapper for MultiFieldElement {
             .apply(
                 header_wrapper(
                     hovered.clone(),
-                    clone!((expanded) move |
+                    clone!((expanded, data) move |
                         In((entity, click)): In<(Entity, Pointer<Click>)>,
                         headers: Query<&HeaderData>,
                         relative_rect: RelativeRect,
                         mut maybe_scroll_to_header_root: MaybeScrollToHeaderRoot,
-                        parents: Query<&Parent>
+                        parents: Query<&Parent>,
+                        edit_settings: Query<&EditSettings>,
+                        keys: Res<ButtonInput<KeyCode>>,
+                        mut commands: Commands
                     | {
+                        // pasting overwrites components, so it requires holding ctrl/cmd
+                        let modifier = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::SuperLeft, KeyCode::SuperRight]);
+                        if let (PointerButton::Secondary, true, MultiFieldData::Entity { id, .. }) = (click.button, modifier, &data) {
+                            let id = *id;
+                            let settings = EditSettings::closest(entity, &parents, &edit_settings);
+                            commands.queue(move |world: &mut World| {
+                                if let Err(e) = paste_component(world, id, settings) {
+                                    warn!("failed to paste component: {e}");
+                                }
+                            });
+                        }
                         if matches!(click.button, PointerButton::Primary) {
                             let mut i = -1;  // don't count current header
                             for ancestor in parents.iter_ancestors(entity) {
@@ -2902,18 +3390,20 @@ impl ElementWrapper for MultiFieldElement {
             Column::<Node>::new()
                 .apply(move_style(Move_::Right, padding.signal()))
                 .apply(left_bordered_style(border_width.signal(), map_bool_signal(hovered.signal(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
-                .items_signal_vec({
+                .apply(|column| {
                     match &data {
                         MultiFieldData::Entity { id: entity, data: EntityData { components, .. } } => {
                             let entity = *entity;
+                            column.apply(virtualized_items(
                             components.entries_cloned()
                             // this is an emulation of something like .sort_by_signal_cloned
                             .map_signal(|(component, data)| {
                                 data.viewability.signal().map(move |cur| (component, data.clone(), cur))
                             })
-                            .sort_by_cloned(|(_, FieldData { name: left_name, .. }, left_viewability), (_, FieldData { name: right_name, .. }, right_viewability)| left_viewability.cmp(right_viewability).reverse().then(type_path_ord(left_name, right_name)))
+                            .sort_by_cloned(|(_, FieldData { name: left_name, .. }, left_viewability), (_, FieldData { name: right_name, .. }, right_viewability)| left_viewability.cmp(right_viewability).reverse().then(type_path_ord(left_name, right_name))),
                             //
-                            .map(clone!((row_gap, column_gap, tertiary_background_color, border_width, border_color, padding, highlighted_color, unhighlighted_color) move |(component, FieldData { name, expanded, viewability, .. }, _)| {
+                            |(_, FieldData { name, .. }, _), part| matches!(part, ProgressPart::Field(field) if lax_type_path_match(field, name)),
+                            clone!((row_gap, column_gap, tertiary_background_color, border_width, border_color, padding, highlighted_color, unhighlighted_color) move |(component, FieldData { name, expanded, viewability, .. }, _)| {
                                 FieldElement::new(FieldElementInput::Component { owner: ComponentOwnerType::Entity(entity), component }, FieldType::Field(name), viewability)
                                 .row_gap_signal(row_gap.signal())
                                 .column_gap_signal(column_gap.signal())
@@ -2924,19 +3414,20 @@ impl ElementWrapper for MultiFieldElement {
                                 .highlighted_color_signal(highlighted_color.signal())
                                 .unhighlighted_color_signal(unhighlighted_color.signal())
                                 .expanded_signal(expanded.signal().dedupe())
-                            }))
-                            .boxed()
+                            })))
                         },
                         MultiFieldData::Asset { id: asset, data: AssetData { handles, .. } } => {
                             let asset = *asset;
+                            column.apply(virtualized_items(
                             handles.entries_cloned()
                             // this is an emulation of something like .sort_by_signal_cloned
                             .map_signal(|(handle, data)| {
                                 data.viewability.signal().map(move |cur| (handle, data.clone(), cur))
                             })
-                            .sort_by_cloned(|(_, FieldData { name: left_name, .. }, left_viewability), (_, FieldData { name: right_name, .. }, right_viewability)| left_viewability.cmp(right_viewability).reverse().then(type_path_ord(left_name, right_name)))
+                            .sort_by_cloned(|(_, FieldData { name: left_name, .. }, left_viewability), (_, FieldData { name: right_name, .. }, right_viewability)| left_viewability.cmp(right_viewability).reverse().then(type_path_ord(left_name, right_name))),
                             //
-                            .map(clone!((row_gap, column_gap, tertiary_background_color, border_width, border_color, padding, highlighted_color, unhighlighted_color) move |(handle, FieldData { name, expanded, viewability, .. }, _)| {
+                            |(_, FieldData { name, .. }, _), part| matches!(part, ProgressPart::Field(field) if lax_type_path_match(field, name)),
+                            clone!((row_gap, column_gap, tertiary_background_color, border_width, border_color, padding, highlighted_color, unhighlighted_color) move |(handle, FieldData { name, expanded, viewability, .. }, _)| {
                                 FieldElement::new(FieldElementInput::Asset { asset, handle }, FieldType::Field(name), viewability)
                                 .row_gap_signal(row_gap.signal())
                                 .column_gap_signal(column_gap.signal())
@@ -2947,8 +3438,7 @@ impl ElementWrapper for MultiFieldElement {
                                 .highlighted_color_signal(highlighted_color.signal())
                                 .unhighlighted_color_signal(unhighlighted_color.signal())
                                 .expanded_signal(expanded.signal().dedupe())
-                            }))
-                            .boxed()
+                            })))
                         }
                     }
                 })
@@ -3065,7 +3555,7 @@ fn populate_enum_with_variant(
 
 #[allow(clippy::too_many_arguments)]
 fn field_header(
-    name: String,
+    name: Mutable<String>,
     field_type: Option<FieldType>,
     type_path: Mutable<Option<String>>,
     viewability: Mutable<Viewability>,
@@ -3076,19 +3566,19 @@ fn field_header(
     unhighlighted_color: Mutable<Color>,
     error_color: Mutable<Color>,
     type_path_color: Mutable<Color>,
-) -> impl Element + CursorOnHoverable {
+) -> Row<Node> {
     Row::<Node>::new()
     .apply(row_style(column_gap.signal()))
     .item_signal(
         viewability.signal().map(|viewability| !matches!(viewability, Viewability::NotInRegistry)).map_bool(
-        clone!((name, highlighted_color, unhighlighted_color, hovered) move || HighlightableText::new().with_text(|text| text.text(name.clone()))
+        clone!((name, highlighted_color, unhighlighted_color, hovered) move || HighlightableText::new().with_text(|text| text.text_signal(name.signal_cloned()))
             .highlighted_color_signal(highlighted_color.signal())
             .unhighlighted_color_signal(unhighlighted_color.signal())
             .highlighted_signal(hovered.signal())
             .type_erase()),
         move || {
             DynamicText::new()
-            .text(name.clone())
+            .text_signal(name.signal_cloned())
             .color_signal(error_color.signal())
             .into_el()
             .type_erase()
@@ -3133,6 +3623,7 @@ fn object_type_header_with_count(
     padding: Mutable<f32>,
     pinned: Mutable<bool>,
     expanded: Mutable<bool>,
+    scene_target: Option<HotkeyTarget>,
 ) -> Column<Node> {
     Column::<Node>::new()
     .width(Val::Percent(100.))
@@ -3221,6 +3712,9 @@ fn object_type_header_with_count(
             .highlighted_color_signal(highlighted_color.signal())
             .unhighlighted_color_signal(unhighlighted_color.signal())
         )
+        .item_signal(hovered.signal().map(move |hovered| scene_target.clone().filter(|_| hovered)).map_some(|target| {
+            scene_actions(target).apply(padding_style([BoxEdge::Left], GLOBAL_COLUMN_GAP.signal()))
+        }))
         .apply(
             header_wrapper(
                 hovered.clone(),
@@ -3261,13 +3755,13 @@ fn object_type_header_with_count(
 #[derive(Component)]
 struct FieldsColumn;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ComponentOwnerType {
     Entity(Entity),
     Resource,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 enum FieldElementInput {
     Component {
         owner: ComponentOwnerType,
@@ -3277,6 +3771,10 @@ enum FieldElementInput {
         asset: TypeId,
         handle: UntypedAssetId,
     },
+    // keyed by the `Events<T>` resource
+    EventDraft {
+        events: ComponentId,
+    },
 }
 
 impl From<FieldElementInput> for AccessoryTarget {
@@ -3286,6 +3784,7 @@ impl From<FieldElementInput> for AccessoryTarget {
                 AccessoryTarget::Component { owner, component }
             }
             FieldElementInput::Asset { asset, handle } => AccessoryTarget::Asset { asset, handle },
+            FieldElementInput::EventDraft { events } => AccessoryTarget::EventDraft { events },
         }
     }
 }
@@ -3317,6 +3816,18 @@ impl FieldElement {
             FieldType::Field(type_path) => (ShortName(&type_path).to_string(), None),
             FieldType::Access(access) => (access.to_string(), Some(access.clone())),
         };
+        let name = Mutable::new(name);
+        let unit = Mutable::new(None);
+        let read_only = Mutable::new(false);
+        let hidden = Mutable::new(false);
+        let slider_range = Mutable::new(None);
+        let raw = Mutable::new(false);
+        let plot = Mutable::new(false);
+        let flash = Mutable::new(0.);
+        let recently_changed = Mutable::new(false);
+        let show_change_ticks = Mutable::new(false);
+        let change_highlight_color = GLOBAL_CHANGE_HIGHLIGHT_COLOR.clone();
+        let header_background_color = Mutable::new(DEFAULT_PRIMARY_BACKGROUND_COLOR);
         let type_path = Mutable::new(None);
         let node_type = Mutable::new(None);
         let enum_data_option = Mutable::new(None);
@@ -3329,12 +3840,37 @@ impl FieldElement {
                 viewability.set_neq(Viewability::Viewable);
             }
         }
+        // whole components are listened to so their headers know when they've changed even when collapsed
+        let component_target_option = match (&field_type, input) {
+            (FieldType::Field(_), FieldElementInput::Component { .. }) => {
+                Some(AccessoryTarget::from(input))
+            }
+            _ => None,
+        };
         let el = Column::<Node>::new()
             .hovered_sync(hovered.clone())
             .width(Val::Percent(100.))
-            .update_raw_el(|raw_el| {
+            .update_raw_el(|mut raw_el| {
+                if let Some(target) = component_target_option {
+                    raw_el = raw_el.insert((Accessory { target, access_option: None }, ComponentChangeListener));
+                }
                 raw_el
+                .hold_tasks([
+                    sync_neq(
+                        map_ref! {
+                            let &primary_background_color = primary_background_color.signal(),
+                            let &change_highlight_color = change_highlight_color.signal(),
+                            let &flash = flash.signal() => {
+                                primary_background_color.mix(&change_highlight_color, flash)
+                            }
+                        },
+                        header_background_color.clone(),
+                    )
+                    .apply(spawn)
+                ])
+                .insert(ChangeHighlight::new(flash.clone(), recently_changed.clone()))
                 .insert(HeaderData { pinned: pinned.clone(), expanded: expanded.clone() })
+                .component_signal::<Hovered, _>(hovered.signal().map_true(default))
                 .insert(PickingBehavior::default())
                 .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
                 .apply(listen_to_expanded_component(expanded.clone()))
@@ -3418,9 +3954,10 @@ impl FieldElement {
                 }))
                 .apply(scroll_to_header_on_birth)
                 .on_spawn_with_system(|In(entity), mut commands: Commands| commands.trigger_targets(CheckInspectionTargets, entity))
-                .on_spawn(clone!((viewability, node_type, type_path, enum_data_option, field_type) move |world, ui_entity| {
+                .on_spawn(clone!((viewability, node_type, type_path, enum_data_option, field_type, name, unit, read_only, hidden, slider_range) move |world, ui_entity| {
                     // TODO: more intelligent way to get this height? waiting for node to reach "full size" is pretty cringe
                     let mut field_path_option = None;
+                    let mut field_attributes_option = None;
                     let type_registry = world.resource::<AppTypeRegistry>().clone();
                     match field_type {
                         FieldType::Field(_) => {
@@ -3443,6 +3980,7 @@ impl FieldElement {
                             field_path_option = Some(field_path);
                         },
                     }
+                    let copy_path = field_path_option.clone().unwrap_or(ParsedPath(vec![]));
                     if let Some(mut reflect) = match input {
                         FieldElementInput::Component { owner, component } => {
                             match owner {
@@ -3453,8 +3991,22 @@ impl FieldElement {
                         FieldElementInput::Asset { asset, handle } => {
                             reflect_asset(world, asset, handle)
                         }
+                        FieldElementInput::EventDraft { events } => reflect_event_draft(world, events),
                     } {
                         if let Some(path) = field_path_option {
+                            if let Some((OffsetAccess { access, .. }, parent_path)) = path.0.split_last() {
+                                let parent = if parent_path.is_empty() {
+                                    Some(reflect.as_partial_reflect())
+                                } else {
+                                    reflect.reflect_path(&ParsedPath(parent_path.to_vec())).ok()
+                                };
+                                field_attributes_option = parent.and_then(|parent| field_custom_attributes(parent, access)).map(FieldAttributes::from);
+                                if let Some(FieldAttributes { slider: true, range: Some(range), .. }) = &field_attributes_option {
+                                    if reflect.reflect_path(&path).ok().and_then(numeric_to_f64).is_some() {
+                                        slider_range.set(Some(range.clone()));
+                                    }
+                                }
+                            }
                             if let Some(result) = reflect.reflect_path(&path).ok().and_then(PartialReflect::try_as_reflect) {
                                 reflect = result;
                             }
@@ -3549,7 +4101,24 @@ impl FieldElement {
                             }
                         }
                     }
+                    if let Ok(mut entity) = world.get_entity_mut(ui_entity) {
+                        entity.insert(HotkeyTarget::Field { target: input.into(), path: copy_path });
+                    }
+                    if let Some(field_attributes) = field_attributes_option {
+                        if let Some(display_name) = field_attributes.display_name {
+                            name.set(display_name.to_string());
+                        }
+                        unit.set(field_attributes.unit);
+                        read_only.set_neq(field_attributes.read_only);
+                        hidden.set_neq(field_attributes.hidden);
+                        if let Ok(mut entity) = world.get_entity_mut(ui_entity) {
+                            entity.insert(field_attributes);
+                        }
+                    }
                 }))
+                .on_signal_with_component::<bool, Node>(hidden.signal(), |mut node, hidden| {
+                    node.display = if hidden { bevy_ui::Display::None } else { bevy_ui::Display::Flex };
+                })
                 .apply(sync_tooltip_position(expected_tooltip_height))
                 .on_signal_with_system(
                     map_ref! {
@@ -3608,16 +4177,139 @@ impl FieldElement {
                     error_color.clone(),
                     type_path_color.clone(),
                 )
-                .apply(
-                    header_wrapper(
-                        hovered.clone(),
-                        clone!((expanded, viewability) move |
-                            In((entity, click)): In<(Entity, Pointer<Click>)>,
-                            headers: Query<&HeaderData>,
-                            relative_rect: RelativeRect,
-                            mut maybe_scroll_to_header_root: MaybeScrollToHeaderRoot,
-                            parents: Query<&Parent>
+                .item_signal(unit.signal().map_some(clone!((type_path_color) move |unit| {
+                    DynamicText::new()
+                    .text(format!("({unit})"))
+                    .color_signal(type_path_color.signal())
+                    .apply(text_no_wrap)
+                })))
+                .item_signal(read_only.signal().map_true(clone!((type_path_color) move || {
+                    DynamicText::new()
+                    .text("read only".to_string())
+                    .color_signal(type_path_color.signal())
+                    .apply(text_no_wrap)
+                })))
+                .item_signal(recently_changed.signal().map(move |changed| changed && component_target_option.is_some()).dedupe().map_true(clone!((change_highlight_color, highlighted_color) move || {
+                    DynamicText::new()
+                    .text("changed".to_string())
+                    .color_signal(map_ref! {
+                        let &change_highlight_color = change_highlight_color.signal(),
+                        let &highlighted_color = highlighted_color.signal() => {
+                            change_highlight_color.mix(&highlighted_color, 0.5)
+                        }
+                    })
+                    .apply(text_no_wrap)
+                })))
+                .item_signal(signal::or(hovered.signal(), show_change_ticks.signal()).map(move |show| show && component_target_option.is_some()).dedupe().map_true(clone!((show_change_ticks, highlighted_color, type_path_color) move || {
+                    DynamicText::new()
+                    .text("ticks".to_string())
+                    .color_signal(map_bool_signal(show_change_ticks.signal(), highlighted_color.clone(), type_path_color.clone()))
+                    .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                    .update_raw_el(clone!((show_change_ticks) move |raw_el| {
+                        raw_el
+                        .insert(PickingBehavior::default())
+                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                            if matches!(click.button, PointerButton::Primary) {
+                                flip(&show_change_ticks);
+                            }
+                        })
+                    }))
+                    .apply(text_no_wrap)
+                })))
+                .item_signal(slider_range.signal_cloned().map_some(clone!((access_option) move |range| numeric_slider(input, access_option.clone(), range))))
+                .item_signal(signal::or(hovered.signal(), raw.signal()).dedupe().map_true(clone!((raw, highlighted_color, type_path_color) move || {
+                    DynamicText::new()
+                    .text("ron".to_string())
+                    .color_signal(map_bool_signal(raw.signal(), highlighted_color.clone(), type_path_color.clone()))
+                    .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                    .update_raw_el(clone!((raw) move |raw_el| {
+                        raw_el
+                        .insert(PickingBehavior::default())
+                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                            if matches!(click.button, PointerButton::Primary) {
+                                flip(&raw);
+                            }
+                        })
+                    }))
+                    .apply(text_no_wrap)
+                })))
+                .item_signal(
+                    map_ref! {
+                        let plottable = type_path.signal_ref(|type_path| type_path.as_deref().and_then(plot_series).is_some()),
+                        let &hovered = hovered.signal(),
+                        let &plot = plot.signal() => {
+                            *plottable && (hovered || plot)
+                        }
+                    }
+                    .dedupe()
+                    .map_true(clone!((plot, highlighted_color, type_path_color) move || {
+                        DynamicText::new()
+                        .text("plot".to_string())
+                        .color_signal(map_bool_signal(plot.signal(), highlighted_color.clone(), type_path_color.clone()))
+                        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                        .update_raw_el(clone!((plot) move |raw_el| {
+                            raw_el
+                            .insert(PickingBehavior::default())
+                            .on_event_with_system_stop_propagation::<Pointer<Click>, _>(move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                                if matches!(click.button, PointerButton::Primary) {
+                                    flip(&plot);
+                                }
+                            })
+                        }))
+                        .apply(text_no_wrap)
+                    }))
+                )
+                .item_signal(hovered.signal().map_true(clone!((type_path_color) move || {
+                    DynamicText::new()
+                    .text("watch".to_string())
+                    .color_signal(type_path_color.signal())
+                    .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                    .update_raw_el(|raw_el| {
+                        raw_el
+                        .insert(PickingBehavior::default())
+                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(|
+                            In((entity, click)): In<(Entity, Pointer<Click>)>,
+                            parents: Query<&Parent>,
+                            hotkey_targets: Query<&HotkeyTarget>,
+                            watch_lists: Query<&WatchList>,
+                            mut commands: Commands,
+                        | {
+                            if !matches!(click.button, PointerButton::Primary) {
+                                return;
+                            }
+                            let Some(HotkeyTarget::Field { target, path }) = parents.iter_ancestors(entity).find_map(|ancestor| hotkey_targets.get(ancestor).ok()).cloned() else { return };
+                            let Some(watch_list) = parents.iter_ancestors(entity).find_map(|ancestor| watch_lists.get(ancestor).ok()).cloned() else { return };
+                            commands.queue(move |world: &mut World| {
+                                if let Some(target) = field_inspection_target(world, target, &path) {
+                                    watch_list.toggle(target);
+                                }
+                            });
+                        })
+                    })
+                    .apply(text_no_wrap)
+                })))
+                .item(
+                    if let FieldElementInput::Asset { asset, handle } = input {
+                        (asset == TypeId::of::<Image>()).then(|| handle.try_typed::<Image>().ok()).flatten()
+                    } else {
+                        None
+                    }
+                    .map(image_thumbnail)
+                )
+                .apply(
+                    header_wrapper(
+                        hovered.clone(),
+                        clone!((expanded, viewability, field_type) move |
+                            In((entity, click)): In<(Entity, Pointer<Click>)>,
+                            headers: Query<&HeaderData>,
+                            relative_rect: RelativeRect,
+                            mut maybe_scroll_to_header_root: MaybeScrollToHeaderRoot,
+                            parents: Query<&Parent>,
+                            mut commands: Commands
                         | {
+                            if let (PointerButton::Secondary, FieldType::Field(_), FieldElementInput::Component { owner: ComponentOwnerType::Entity(owner), component }) = (click.button, &field_type, input) {
+                                commands.queue(move |world: &mut World| copy_component(world, owner, component));
+                            }
                             if matches!(viewability.get(), Viewability::Viewable) && matches!(click.button, PointerButton::Primary) {
                                 let mut i = -1;  // don't count current header
                                 for ancestor in parents.iter_ancestors(entity) {
@@ -3635,7 +4327,7 @@ impl FieldElement {
                             }
                         }),
                         row_gap.clone(),
-                        primary_background_color,
+                        header_background_color,
                         secondary_background_color,
                         padding.clone(),
                         pinned.clone(),
@@ -3644,7 +4336,30 @@ impl FieldElement {
                 .cursor_disableable_signal(CursorIcon::System(SystemCursorIcon::Pointer), viewability.signal().map(|viewability| !matches!(viewability, Viewability::Viewable)))
                 .z_index(ZIndex(i32::MAX))
             })
-            .item_signal(expanded.signal().dedupe().map_true(
+            .item_signal(show_change_ticks.signal().dedupe().map_true(clone!((row_gap, padding) move || {
+                match component_target_option {
+                    Some(AccessoryTarget::Component { owner, component }) => {
+                        El::<Node>::new()
+                        .apply(padding_style(BoxEdge::VERTICAL, row_gap.signal().map(div(2.))))
+                        .apply(padding_style(BoxEdge::HORIZONTAL, padding.signal()))
+                        .child(change_ticks(owner, component))
+                        .apply(Some)
+                    }
+                    _ => None,
+                }
+            })).map(Option::flatten))
+            .item_signal(
+                map_ref! {
+                    let &plot = plot.signal(),
+                    let series = type_path.signal_ref(|type_path| type_path.as_deref().and_then(plot_series)) => {
+                        series.filter(|_| plot)
+                    }
+                }
+                .dedupe()
+                .map_some(clone!((access_option) move |series| plot_field(input, access_option.clone(), series)))
+            )
+            .item_signal(raw.signal().dedupe().map_true(clone!((access_option) move || ron_field(input, access_option.clone()))))
+            .item_signal(signal::and(expanded.signal(), signal::not(raw.signal())).dedupe().map_true(
                 clone!((
                     row_gap,
                     border_width,
@@ -3723,27 +4438,34 @@ impl FieldElement {
                                         In(i): In<usize>,
                                         accessories: Query<&Accessory>,
                                         mut field_path_cached: FieldPathCached,
+                                        closest_field_attributes: ClosestFieldAttributes,
+                                        parents: Query<&Parent>,
+                                        edit_settings: Query<&EditSettings>,
                                         type_registry: Res<AppTypeRegistry>,
                                         mut commands: Commands,
                                     | {
                                         let ui_entity = dropdown_entity.get().unwrap();
+                                        if closest_field_attributes.read_only(ui_entity) {
+                                            show_dropdown.set_neq(false);
+                                            return;
+                                        }
                                         if let Ok(&Accessory { target, .. }) = accessories.get(ui_entity) {
                                             let field_path = field_path_cached.get(ui_entity);
+                                            let settings = EditSettings::closest(ui_entity, &parents, &edit_settings);
                                             let type_registry = type_registry.0.clone();
                                             commands.queue(clone!((node_type) move |world: &mut World| {
-                                                let f = |reflect: &mut dyn Reflect| {
-                                                    if let Ok(target) = reflect.reflect_path_mut(&field_path) {
-                                                        if let ReflectMut::Enum(enum_) = target.reflect_mut() {
-                                                            if let Some(variant_info) = get_variant_info(enum_, i) {
-                                                                if let Some(default) = variant_default_value(variant_info, &type_registry.read()) {
-                                                                    populate_enum_with_variant(enum_, i, &node_type);
-                                                                    let _ = target.try_apply(&default);
-                                                                }
-                                                            }
+                                                let Some(default) = reflect_accessory_target(world, target)
+                                                    .and_then(|reflect| reflect.reflect_path(&field_path).ok())
+                                                    .and_then(|field| match field.reflect_ref() {
+                                                        ReflectRef::Enum(enum_) => {
+                                                            let default = variant_default_value(get_variant_info(enum_, i)?, &type_registry.read())?;
+                                                            populate_enum_with_variant(enum_, i, &node_type);
+                                                            Some(default)
                                                         }
-                                                    }
-                                                };
-                                                apply_to_accessory_target(world, target, f);
+                                                        _ => None,
+                                                    })
+                                                else { return };
+                                                let _ = edit_field(world, target, &field_path, &default, settings);
                                             }));
                                         }
                                         show_dropdown.set_neq(false);
@@ -3810,9 +4532,10 @@ impl FieldElement {
                                         }
                                         raw_el
                                     }))
-                                    .items_signal_vec(
-                                        items.signal_vec_cloned()
-                                        .map(clone!((row_gap, border_width, border_color, padding, highlighted_color, unhighlighted_color, type_path_color) move |AccessFieldData { access, viewability }| {
+                                    .apply(virtualized_items(
+                                        items.signal_vec_cloned(),
+                                        |AccessFieldData { access, .. }, part| matches!(part, ProgressPart::Access(target) if target == access),
+                                        clone!((row_gap, border_width, border_color, padding, highlighted_color, unhighlighted_color, type_path_color) move |AccessFieldData { access, viewability }| {
                                             FieldElement::new(input, FieldType::Access(access.clone()), viewability)
                                             .row_gap_signal(row_gap.signal())
                                             .border_width_signal(border_width.signal())
@@ -3821,8 +4544,8 @@ impl FieldElement {
                                             .highlighted_color_signal(highlighted_color.signal())
                                             .unhighlighted_color_signal(unhighlighted_color.signal())
                                             .type_path_color_signal(type_path_color.signal())
-                                        }))
-                                    )
+                                        })
+                                    ))
                                     .type_erase()
                                     .apply(Some)
                                 },
@@ -3935,6 +4658,19 @@ static FRONTENDS: Lazy<
     .apply(RwLock::new)
 });
 
+// matched by type path prefix, for generic types whose frontends don't depend on their type parameters
+#[allow(clippy::type_complexity)]
+#[rustfmt::skip]
+static GENERIC_FRONTENDS: Lazy<
+    RwLock<Vec<(&'static str, Box<dyn Fn() -> AlignabilityFacade + Send + Sync + 'static>)>>,
+> = Lazy::new(|| {
+    vec![
+        ("bevy_asset::handle::Handle<", Box::new(|| handle_field().type_erase()) as Box<_>),
+        ("core::option::Option<", Box::new(|| option_field().type_erase()) as Box<_>),
+    ]
+    .apply(RwLock::new)
+});
+
 #[allow(clippy::type_complexity)]
 static CUSTOM_FRONTENDS: Lazy<
     RwLock<HashMap<&'static str, Box<dyn Fn() -> AlignabilityFacade + Send + Sync + 'static>>>,
@@ -4069,6 +4805,11 @@ fn bool_vec_field(fields: &'static [&str]) -> impl Element {
 pub fn has_frontend(type_path: &str) -> bool {
     FRONTENDS.read().unwrap().contains_key(type_path)
         || CUSTOM_FRONTENDS.read().unwrap().contains_key(type_path)
+        || GENERIC_FRONTENDS
+            .read()
+            .unwrap()
+            .iter()
+            .any(|(prefix, _)| type_path.starts_with(prefix))
 }
 
 pub fn frontend(type_path: &str) -> Option<impl Element> {
@@ -4078,6 +4819,14 @@ pub fn frontend(type_path: &str) -> Option<impl Element> {
         .get(type_path)
         .map(|f| f())
         .or_else(|| FRONTENDS.read().unwrap().get(type_path).map(|f| f()))
+        .or_else(|| {
+            GENERIC_FRONTENDS
+                .read()
+                .unwrap()
+                .iter()
+                .find(|(prefix, _)| type_path.starts_with(prefix))
+                .map(|(_, f)| f())
+        })
 }
 
 #[derive(SystemParam)]
@@ -4137,29 +4886,15 @@ pub fn variant_default_value(
         VariantInfo::Struct(struct_) => {
             let mut dynamic_struct = DynamicStruct::default();
             for field in struct_.iter() {
-                if let Some(reflect_default) =
-                    registry.get_type_data::<ReflectDefault>(field.type_id())
-                {
-                    dynamic_struct.insert_boxed(
-                        field.name(),
-                        reflect_default.default().into_partial_reflect(),
-                    );
-                } else {
-                    return None;
-                }
+                dynamic_struct
+                    .insert_boxed(field.name(), default_value(field.type_id(), registry)?);
             }
             Some(DynamicEnum::new(variant.name(), dynamic_struct))
         }
         VariantInfo::Tuple(tuple) => {
             let mut dynamic_tuple = DynamicTuple::default();
             for field in tuple.iter() {
-                if let Some(reflect_default) =
-                    registry.get_type_data::<ReflectDefault>(field.type_id())
-                {
-                    dynamic_tuple.insert_boxed(reflect_default.default().into_partial_reflect());
-                } else {
-                    return None;
-                }
+                dynamic_tuple.insert_boxed(default_value(field.type_id(), registry)?);
             }
             Some(DynamicEnum::new(variant.name(), dynamic_tuple))
         }
@@ -4167,24 +4902,235 @@ pub fn variant_default_value(
     }
 }
 
+// uses `ReflectDefault` if available, otherwise recursively builds a dynamic value from the type's fields
+pub fn default_value(type_id: TypeId, registry: &TypeRegistry) -> Option<Box<dyn PartialReflect>> {
+    let registration = registry.get(type_id)?;
+    if let Some(reflect_default) = registration.data::<ReflectDefault>() {
+        return Some(reflect_default.default().into_partial_reflect());
+    }
+    let type_info = Some(registration.type_info());
+    Some(match registration.type_info() {
+        TypeInfo::Struct(struct_) => {
+            let mut dynamic_struct = DynamicStruct::default();
+            for field in struct_.iter() {
+                dynamic_struct
+                    .insert_boxed(field.name(), default_value(field.type_id(), registry)?);
+            }
+            dynamic_struct.set_represented_type(type_info);
+            Box::new(dynamic_struct)
+        }
+        TypeInfo::TupleStruct(tuple_struct) => {
+            let mut dynamic_tuple_struct = DynamicTupleStruct::default();
+            for field in tuple_struct.iter() {
+                dynamic_tuple_struct.insert_boxed(default_value(field.type_id(), registry)?);
+            }
+            dynamic_tuple_struct.set_represented_type(type_info);
+            Box::new(dynamic_tuple_struct)
+        }
+        TypeInfo::Tuple(tuple) => {
+            let mut dynamic_tuple = DynamicTuple::default();
+            for field in tuple.iter() {
+                dynamic_tuple.insert_boxed(default_value(field.type_id(), registry)?);
+            }
+            dynamic_tuple.set_represented_type(type_info);
+            Box::new(dynamic_tuple)
+        }
+        TypeInfo::Array(array) => {
+            let mut items = vec![];
+            for _ in 0..array.capacity() {
+                items.push(default_value(array.item_ty().id(), registry)?);
+            }
+            let mut dynamic_array = DynamicArray::new(items.into_boxed_slice());
+            dynamic_array.set_represented_type(type_info);
+            Box::new(dynamic_array)
+        }
+        TypeInfo::List(_) => {
+            let mut dynamic_list = DynamicList::default();
+            dynamic_list.set_represented_type(type_info);
+            Box::new(dynamic_list)
+        }
+        TypeInfo::Map(_) => {
+            let mut dynamic_map = DynamicMap::default();
+            dynamic_map.set_represented_type(type_info);
+            Box::new(dynamic_map)
+        }
+        TypeInfo::Set(_) => {
+            let mut dynamic_set = DynamicSet::default();
+            dynamic_set.set_represented_type(type_info);
+            Box::new(dynamic_set)
+        }
+        TypeInfo::Enum(enum_) => {
+            let mut dynamic_enum = enum_
+                .iter()
+                .find_map(|variant| variant_default_value(variant, registry))?;
+            dynamic_enum.set_represented_type(type_info);
+            Box::new(dynamic_enum)
+        }
+        TypeInfo::Opaque(_) => return None,
+    })
+}
+
+fn field_custom_attributes(
+    parent: &dyn PartialReflect,
+    access: &Access,
+) -> Option<&'static CustomAttributes> {
+    match (parent.get_represented_type_info()?, access) {
+        (TypeInfo::Struct(struct_info), Access::Field(name)) => {
+            struct_info.field(name).map(NamedField::custom_attributes)
+        }
+        (TypeInfo::Struct(struct_info), Access::FieldIndex(i)) => {
+            struct_info.field_at(*i).map(NamedField::custom_attributes)
+        }
+        (TypeInfo::TupleStruct(tuple_struct_info), Access::TupleIndex(i)) => tuple_struct_info
+            .field_at(*i)
+            .map(UnnamedField::custom_attributes),
+        (TypeInfo::Enum(enum_info), access) => {
+            let ReflectRef::Enum(enum_) = parent.reflect_ref() else {
+                return None;
+            };
+            match (enum_info.variant(enum_.variant_name())?, access) {
+                (VariantInfo::Struct(struct_info), Access::Field(name)) => {
+                    struct_info.field(name).map(NamedField::custom_attributes)
+                }
+                (VariantInfo::Tuple(tuple_info), Access::TupleIndex(i)) => {
+                    tuple_info.field_at(*i).map(UnnamedField::custom_attributes)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// attributes of the closest field element, if any
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub struct ClosestFieldAttributes<'w, 's> {
+    parents: Query<'w, 's, &'static Parent>,
+    field_attributes: Query<'w, 's, Option<&'static FieldAttributes>, With<HeaderData>>,
+}
+
+impl<'w, 's> ClosestFieldAttributes<'w, 's> {
+    pub fn get(&self, entity: Entity) -> Option<&FieldAttributes> {
+        for ancestor in [entity]
+            .into_iter()
+            .chain(self.parents.iter_ancestors(entity))
+        {
+            if let Ok(field_attributes_option) = self.field_attributes.get(ancestor) {
+                return field_attributes_option;
+            }
+        }
+        None
+    }
+
+    /// Whether the field or any field it's nested in is read only.
+    pub fn read_only(&self, entity: Entity) -> bool {
+        [entity]
+            .into_iter()
+            .chain(self.parents.iter_ancestors(entity))
+            .any(|ancestor| {
+                matches!(
+                    self.field_attributes.get(ancestor),
+                    Ok(Some(FieldAttributes {
+                        read_only: true,
+                        ..
+                    }))
+                )
+            })
+    }
+}
+
+/// How an inspector writes edits made through its fields.
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub struct EditSettings {
+    /// Skip edits equal to the current value.
+    pub skip_unchanged: bool,
+    /// Bypass change detection when writing to components and resources.
+    pub silent: bool,
+}
+
+impl EditSettings {
+    // the settings of the inspector `entity` is in
+    fn closest(
+        entity: Entity,
+        parents: &Query<&Parent>,
+        edit_settings: &Query<&EditSettings>,
+    ) -> Self {
+        parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| edit_settings.get(ancestor).ok())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn closest_in_world(world: &mut World, entity: Entity) -> Self {
+        let mut system_state = SystemState::<(Query<&Parent>, Query<&EditSettings>)>::new(world);
+        let (parents, edit_settings) = system_state.get(world);
+        Self::closest(entity, &parents, &edit_settings)
+    }
+}
+
+// writes `value` to the field at `path` of `target` as configured by `settings`
+fn edit_field(
+    world: &mut World,
+    target: AccessoryTarget,
+    path: &ParsedPath,
+    value: &dyn PartialReflect,
+    EditSettings {
+        skip_unchanged,
+        silent,
+    }: EditSettings,
+) -> Result<(), String> {
+    if skip_unchanged
+        && reflect_accessory_target(world, target)
+            .and_then(|reflect| reflect.reflect_path(path).ok())
+            .and_then(|cur| cur.reflect_partial_eq(value))
+            == Some(true)
+    {
+        return Ok(());
+    }
+    let mut result = Err(format!("{path} does not exist"));
+    edit_accessory_target(world, target, silent, |reflect| {
+        result = reflect
+            .reflect_path_mut(path)
+            .map_err(|e| e.to_string())
+            .and_then(|field| field.try_apply(value).map_err(|e| e.to_string()));
+    });
+    result
+}
+
+// writes a field's edits instead of reflection, e.g. to go through a resource's own methods
+#[allow(clippy::type_complexity)]
+#[derive(Component, Clone)]
+struct FieldSetter(Arc<dyn Fn(&mut World, &dyn PartialReflect) + Send + Sync>);
+
 #[derive(SystemParam)]
 pub struct TargetField<'w, 's> {
     accessories: Query<'w, 's, &'static Accessory>,
+    setters: Query<'w, 's, &'static FieldSetter>,
     field_path_cached: FieldPathCached<'w, 's>,
+    closest_field_attributes: ClosestFieldAttributes<'w, 's>,
+    parents: Query<'w, 's, &'static Parent>,
+    edit_settings: Query<'w, 's, &'static EditSettings>,
     commands: Commands<'w, 's>,
 }
 
 impl<'w, 's> TargetField<'w, 's> {
     pub fn update(&mut self, entity: Entity, value: Box<dyn PartialReflect>) {
+        if self.closest_field_attributes.read_only(entity) {
+            return;
+        }
+        if let Ok(FieldSetter(set)) = self.setters.get(entity) {
+            let set = set.clone();
+            self.commands
+                .queue(move |world: &mut World| set(world, &*value));
+            return;
+        }
         if let Ok(&Accessory { target, .. }) = self.accessories.get(entity) {
             let field_path = self.field_path_cached.get(entity);
+            let settings = EditSettings::closest(entity, &self.parents, &self.edit_settings);
             self.commands.queue(move |world: &mut World| {
-                let f = |reflect: &mut dyn Reflect| {
-                    if let Ok(target) = reflect.reflect_path_mut(&field_path) {
-                        let _ = target.try_apply(&*value);
-                    }
-                };
-                apply_to_accessory_target(world, target, f);
+                let _ = edit_field(world, target, &field_path, &*value, settings);
             });
         }
     }
@@ -4273,76 +5219,423 @@ pub fn entity_field() -> impl Element {
         }))
 }
 
-#[allow(clippy::type_complexity)]
-#[derive(Default)]
-pub struct TextInputField<T, F> {
-    el: TextInput,
-    initial: T,
-    formatter: F,
-    highlight: Mutable<bool>, // wrap in option when don't want to provide impl_syncer
-    border_color_option: Option<SyncBoxSignal<'static, Option<Color>>>,
-    text_color_option: Option<SyncBoxSignal<'static, Option<Color>>>,
-    focused: Option<Mutable<bool>>,
-    value: Option<Mutable<T>>,
-    with_text_signal: Vec<Box<dyn FnMut(TextInput, BoxSignal<'static, String>) -> TextInput>>,
+#[derive(Clone, PartialEq)]
+struct HandleData {
+    asset: &'static str,
+    name: String,
+    strong: bool,
+    load_state: Option<&'static str>,
+    image: Option<AssetId<Image>>,
 }
 
-impl<T, F> TextInputField<T, F> {
-    pub fn new(initial: T, formatter: F) -> Self {
-        Self {
-            el: TextInput::new(),
-            initial,
-            formatter,
-            highlight: Mutable::new(false),
-            border_color_option: None,
-            text_color_option: None,
-            focused: None,
-            value: None,
-            with_text_signal: vec![],
-        }
-    }
-
-    pub fn with_value(mut self, value: Mutable<T>) -> Self {
-        self.value = Some(value);
-        self
+fn load_state_name(load_state: &LoadState) -> &'static str {
+    match load_state {
+        LoadState::NotLoaded => "not loaded",
+        LoadState::Loading => "loading",
+        LoadState::Loaded => "loaded",
+        LoadState::Failed(_) => "failed",
     }
+}
 
-    pub fn with_focused(mut self, focused: Mutable<bool>) -> Self {
-        self.focused = Some(focused);
-        self
-    }
+pub fn handle_field() -> impl Element {
+    let font_size = GLOBAL_FONT_SIZE.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let unhighlighted_color = GLOBAL_UNHIGHLIGHTED_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let error_color = GLOBAL_ERROR_COLOR.clone();
+    let handle_data: Mutable<Option<HandleData>> = Mutable::new(None);
+    El::<Node>::new()
+        .update_raw_el(clone!((handle_data) move |raw_el| {
+            raw_el.with_entity(move |mut entity| {
+                let handler = entity.world_scope(|world| {
+                    register_system(world, move |
+                        In(reflect): In<Box<dyn PartialReflect>>,
+                        type_registry: Res<AppTypeRegistry>,
+                        asset_server: Res<AssetServer>,
+                    | {
+                        let type_registry = type_registry.read();
+                        let Some(type_id) = reflect.get_represented_type_info().map(TypeInfo::type_id) else { return };
+                        // the listened value is a dynamic clone, so it must be converted back into a concrete `Handle<T>` before it can be untyped
+                        if let Some((reflect_handle, handle)) = type_registry
+                            .get_type_data::<ReflectHandle>(type_id)
+                            .zip(type_registry.get_type_data::<ReflectFromReflect>(type_id))
+                            .and_then(|(reflect_handle, reflect_from_reflect)| {
+                                reflect_from_reflect
+                                    .from_reflect(&*reflect)
+                                    .and_then(|handle| reflect_handle.downcast_handle_untyped(handle.as_any()))
+                                    .map(|handle| (reflect_handle, handle))
+                            })
+                        {
+                            if let Some(asset) = type_registry.get_type_info(reflect_handle.asset_type_id()).map(TypeInfo::type_path) {
+                                let id = handle.id();
+                                handle_data.set_neq(Some(HandleData {
+                                    asset,
+                                    name: handle_name(id, &asset_server),
+                                    strong: matches!(handle, UntypedHandle::Strong(_)),
+                                    load_state: asset_server.get_load_state(id).as_ref().map(load_state_name),
+                                    image: id.try_typed::<Image>().ok(),
+                                }));
+                            }
+                        }
+                    })
+                });
+                entity.insert(FieldListener { handler });
+            })
+        }))
+        .child_signal(handle_data.signal_cloned().map_some(move |HandleData { asset, name, strong, load_state, image }| {
+            let hovered = Mutable::new(false);
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .item(
+                    El::<Node>::new()
+                        .hovered_sync(hovered.clone())
+                        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                        .on_click_with_system(clone!((name) move |
+                            In((entity, click)): In<(Entity, Pointer<Click>)>,
+                            parents: Query<&Parent>,
+                            inspection_target_holders: Query<&InspectionTargetHolder>,
+                            mut commands: Commands,
+                        | {
+                            if matches!(click.button, PointerButton::Primary) {
+                                if let Some(holder) = parents.iter_ancestors(entity).find(|&ancestor| inspection_target_holders.contains(ancestor)) {
+                                    if let Some(mut entity) = commands.get_entity(holder) {
+                                        entity.try_insert(InspectionTarget::from((InspectionTargetRoot::Asset, asset, name.as_str())));
+                                    }
+                                }
+                            }
+                        }))
+                        .child(
+                            HighlightableText::new()
+                            .highlighted_signal(hovered.signal())
+                            .with_text(clone!((font_size) move |text| {
+                                text
+                                .text(name)
+                                .font_size_signal(font_size.signal())
+                                .apply(text_no_wrap)
+                            }))
+                            .highlighted_color_signal(highlighted_color.signal())
+                            .unhighlighted_color_signal(unhighlighted_color.signal())
+                        )
+                )
+                .item(image.map(image_thumbnail))
+                .item(
+                    DynamicText::new()
+                    .text(if strong { "strong" } else { "weak" }.to_string())
+                    .font_size_signal(font_size.signal())
+                    .color_signal(tertiary_background_color.signal())
+                    .apply(text_no_wrap)
+                )
+                .item(load_state.map(clone!((font_size, tertiary_background_color, error_color) move |load_state| {
+                    DynamicText::new()
+                    .text(load_state.to_string())
+                    .font_size_signal(font_size.signal())
+                    .color_signal(if load_state == "failed" { error_color.signal() } else { tertiary_background_color.signal() })
+                    .apply(text_no_wrap)
+                })))
+        }))
+}
 
-    pub fn with_highlight(mut self, highlight: Mutable<bool>) -> Self {
-        self.highlight = highlight;
-        self
+impl From<AccessoryTarget> for FieldElementInput {
+    fn from(target: AccessoryTarget) -> Self {
+        match target {
+            AccessoryTarget::Component { owner, component } => {
+                FieldElementInput::Component { owner, component }
+            }
+            AccessoryTarget::Asset { asset, handle } => FieldElementInput::Asset { asset, handle },
+            AccessoryTarget::EventDraft { events } => FieldElementInput::EventDraft { events },
+        }
     }
+}
 
-    pub fn with_border_color_option(
-        mut self,
-        border_color_option: impl Signal<Item = Option<Color>> + Send + Sync + 'static,
-    ) -> Self {
-        self.border_color_option = Some(boxed_sync(border_color_option));
-        self
+fn option_inner_type(enum_: &dyn Enum) -> Option<(&'static str, TypeId)> {
+    if let Some(TypeInfo::Enum(enum_info)) = enum_.get_represented_type_info() {
+        if let Some(VariantInfo::Tuple(tuple_info)) = enum_info.variant("Some") {
+            return tuple_info
+                .field_at(0)
+                .map(|field| (field.type_path(), field.type_id()));
+        }
     }
+    None
+}
 
-    pub fn with_text_color_option(
-        mut self,
-        text_color_option: impl Signal<Item = Option<Color>> + Send + Sync + 'static,
-    ) -> Self {
-        self.text_color_option = Some(boxed_sync(text_color_option));
-        self
+// `ReflectDefault`, then `ReflectFromWorld`, then a recursively constructed default
+fn option_inner_value(world: &mut World, type_id: TypeId) -> Option<Box<dyn PartialReflect>> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    if let Some(reflect_default) = type_registry.get_type_data::<ReflectDefault>(type_id) {
+        return Some(reflect_default.default().into_partial_reflect());
     }
-
-    pub fn with_text_signal(
-        mut self,
-        f: Box<dyn FnMut(TextInput, BoxSignal<'static, String>) -> TextInput>,
-    ) -> Self {
-        self.with_text_signal.push(f);
-        self
+    if let Some(reflect_from_world) = type_registry.get_type_data::<ReflectFromWorld>(type_id) {
+        return Some(reflect_from_world.from_world(world).into_partial_reflect());
     }
+    default_value(type_id, &type_registry)
 }
 
-impl<
+pub fn option_field() -> impl Element {
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let is_some = Mutable::new(false);
+    let inner_option = Mutable::new(None);
+    let input_option = Mutable::new(None);
+    Row::<Node>::new()
+        .apply(row_style(column_gap.signal()))
+        .update_raw_el(clone!((is_some, inner_option, input_option) move |raw_el| {
+            raw_el.with_entity(move |mut entity| {
+                let ui_entity = entity.id();
+                let handler = entity.world_scope(|world| {
+                    register_system(world, move |In(reflect): In<Box<dyn PartialReflect>>, accessories: Query<&Accessory>| {
+                        if let ReflectRef::Enum(enum_) = reflect.reflect_ref() {
+                            is_some.set_neq(enum_.variant_name() == "Some");
+                            if inner_option.lock_ref().is_none() {
+                                inner_option.set(option_inner_type(enum_));
+                            }
+                        }
+                        if input_option.lock_ref().is_none() {
+                            if let Ok(&Accessory { target, .. }) = accessories.get(ui_entity) {
+                                input_option.set(Some(FieldElementInput::from(target)));
+                            }
+                        }
+                    })
+                });
+                entity.insert(FieldListener { handler });
+            })
+        }))
+        .item(
+            Checkbox::new()
+            .checked_signal(is_some.signal())
+            .on_click_with_system(clone!((is_some, inner_option) move |
+                In((checkbox, click)): In<(Entity, Pointer<Click>)>,
+                parents: Query<&Parent>,
+                accessories: Query<&Accessory>,
+                mut field_path_cached: FieldPathCached,
+                closest_field_attributes: ClosestFieldAttributes,
+                edit_settings: Query<&EditSettings>,
+                mut commands: Commands,
+            | {
+                if matches!(click.button, PointerButton::Primary) {
+                    let Ok(ui_entity) = parents.get(checkbox).map(Parent::get) else { return };
+                    if closest_field_attributes.read_only(ui_entity) { return }
+                    let Ok(&Accessory { target, .. }) = accessories.get(ui_entity) else { return };
+                    let field_path = field_path_cached.get(ui_entity);
+                    let settings = EditSettings::closest(ui_entity, &parents, &edit_settings);
+                    let is_some = is_some.get();
+                    let inner_option = inner_option.get();
+                    commands.queue(move |world: &mut World| {
+                        let value = if is_some {
+                            DynamicEnum::new("None", DynamicVariant::Unit)
+                        } else {
+                            let Some((_, inner)) = inner_option else { return };
+                            let Some(inner) = option_inner_value(world, inner) else { return };
+                            let mut dynamic_tuple = DynamicTuple::default();
+                            dynamic_tuple.insert_boxed(inner);
+                            DynamicEnum::new("Some", dynamic_tuple)
+                        };
+                        let _ = edit_field(world, target, &field_path, &value, settings);
+                    });
+                }
+            }))
+        )
+        .item_signal(
+            map_ref! {
+                let &is_some = is_some.signal(),
+                let &inner_option = inner_option.signal(),
+                let &input_option = input_option.signal() => {
+                    if is_some {
+                        inner_option.zip(input_option)
+                    } else {
+                        None
+                    }
+                }
+            }
+            .map_some(|((type_path, _), input)| {
+                if let Some(el) = frontend(type_path) {
+                    el.apply(append_access(Access::TupleIndex(0))).type_erase()
+                } else {
+                    FieldElement::new(input, FieldType::Access(Access::TupleIndex(0)), Mutable::new(Viewability::Viewable))
+                    .row_gap_signal(GLOBAL_ROW_GAP.signal())
+                    .border_width_signal(GLOBAL_BORDER_WIDTH.signal())
+                    .border_color_signal(GLOBAL_BORDER_COLOR.signal())
+                    .padding_signal(GLOBAL_PADDING.signal())
+                    .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                    .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal())
+                    .type_path_color_signal(GLOBAL_TERTIARY_BACKGROUND_COLOR.signal())
+                    .type_erase()
+                }
+            })
+        )
+}
+
+const ENLARGED_THUMBNAIL_SIZE: f32 = 256.;
+
+#[derive(Clone, Copy, PartialEq)]
+struct ImageInfo {
+    size: UVec2,
+    format: TextureFormat,
+}
+
+#[derive(Component)]
+struct ImageThumbnail {
+    image: AssetId<Image>,
+    info: Mutable<Option<ImageInfo>>,
+}
+
+fn sync_image_thumbnails(thumbnails: Query<&ImageThumbnail>, images: Res<Assets<Image>>) {
+    for ImageThumbnail { image, info } in thumbnails.iter() {
+        info.set_neq(images.get(*image).map(|image| ImageInfo {
+            size: image.size(),
+            format: image.texture_descriptor.format,
+        }));
+    }
+}
+
+pub fn image_thumbnail(image: AssetId<Image>) -> impl Element {
+    let font_size = GLOBAL_FONT_SIZE.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let info = Mutable::new(None);
+    Row::<Node>::new()
+        .apply(row_style(column_gap.signal()))
+        .update_raw_el(clone!((info) move |raw_el| raw_el.insert(ImageThumbnail { image, info })))
+        .items_signal_vec(info.signal().map(move |info_option| {
+            let mut items = vec![];
+            if let Some(ImageInfo { size, format }) = info_option {
+                let aspect_ratio = size.x as f32 / size.y.max(1) as f32;
+                let enlarged_size = if aspect_ratio >= 1. {
+                    Vec2::new(ENLARGED_THUMBNAIL_SIZE, ENLARGED_THUMBNAIL_SIZE / aspect_ratio)
+                } else {
+                    Vec2::new(ENLARGED_THUMBNAIL_SIZE * aspect_ratio, ENLARGED_THUMBNAIL_SIZE)
+                };
+                let hovered = Mutable::new(false);
+                let enlarged = Mutable::new(false);
+                // TODO: more intelligent way to get this height? waiting for node to reach "full size" is pretty cringe
+                let expected_tooltip_height = enlarged_size.y + GLOBAL_PADDING.get() + GLOBAL_BORDER_WIDTH.get() * 2. + 3.;
+                items.push(
+                    El::<ImageNode>::new()
+                    .image_node(ImageNode::new(Handle::Weak(image)))
+                    .height_signal(font_size.signal().map(Val::Px))
+                    .width_signal(font_size.signal().map(move |font_size| Val::Px(font_size * aspect_ratio)))
+                    .cursor(CursorIcon::System(SystemCursorIcon::ZoomIn))
+                    .hovered_sync(hovered.clone())
+                    .update_raw_el(clone!((enlarged) move |raw_el| {
+                        raw_el
+                        .insert(PickingBehavior::default())
+                        .apply(sync_tooltip_position(expected_tooltip_height))
+                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(clone!((enlarged) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                            if matches!(click.button, PointerButton::Primary) {
+                                flip(&enlarged);
+                            }
+                        }))
+                        .on_signal_with_system(
+                            signal::and(hovered.signal(), enlarged.signal()).dedupe(),
+                            move |In((entity, show)), mut tooltip_cache: TooltipCache| {
+                                if let Some(tooltip) = tooltip_cache.get(entity) {
+                                    let data = Some(TooltipData::new(entity, String::new()).with_image(Handle::Weak(image), enlarged_size));
+                                    let mut lock = tooltip.lock_mut();
+                                    if show {
+                                        if *lock != data {
+                                            *lock = data;
+                                        }
+                                    } else if *lock == data {
+                                        *lock = None;
+                                    }
+                                }
+                            }
+                        )
+                    }))
+                    .on_hovered_change(move |is_hovered| if !is_hovered { enlarged.set_neq(false) })
+                    .type_erase()
+                );
+                items.push(
+                    DynamicText::new()
+                    .text(format!("{}x{} {:?}", size.x, size.y, format))
+                    .font_size_signal(font_size.signal())
+                    .color_signal(tertiary_background_color.signal())
+                    .apply(text_no_wrap)
+                    .type_erase()
+                );
+            }
+            items
+        }).to_signal_vec())
+}
+
+#[allow(clippy::type_complexity)]
+#[derive(Default)]
+pub struct TextInputField<T, F> {
+    el: TextInput,
+    initial: T,
+    formatter: F,
+    highlight: Mutable<bool>, // wrap in option when don't want to provide impl_syncer
+    border_color_option: Option<SyncBoxSignal<'static, Option<Color>>>,
+    text_color_option: Option<SyncBoxSignal<'static, Option<Color>>>,
+    focused: Option<Mutable<bool>>,
+    value: Option<Mutable<T>>,
+    refresh: Option<Mutable<()>>,
+    with_text_signal: Vec<Box<dyn FnMut(TextInput, BoxSignal<'static, String>) -> TextInput>>,
+}
+
+impl<T, F> TextInputField<T, F> {
+    pub fn new(initial: T, formatter: F) -> Self {
+        Self {
+            el: TextInput::new(),
+            initial,
+            formatter,
+            highlight: Mutable::new(false),
+            border_color_option: None,
+            text_color_option: None,
+            focused: None,
+            value: None,
+            refresh: None,
+            with_text_signal: vec![],
+        }
+    }
+
+    pub fn with_value(mut self, value: Mutable<T>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Setting `refresh` rewrites the text from the value, e.g. to discard edits that never reached the value.
+    pub fn with_refresh(mut self, refresh: Mutable<()>) -> Self {
+        self.refresh = Some(refresh);
+        self
+    }
+
+    pub fn with_focused(mut self, focused: Mutable<bool>) -> Self {
+        self.focused = Some(focused);
+        self
+    }
+
+    pub fn with_highlight(mut self, highlight: Mutable<bool>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    pub fn with_border_color_option(
+        mut self,
+        border_color_option: impl Signal<Item = Option<Color>> + Send + Sync + 'static,
+    ) -> Self {
+        self.border_color_option = Some(boxed_sync(border_color_option));
+        self
+    }
+
+    pub fn with_text_color_option(
+        mut self,
+        text_color_option: impl Signal<Item = Option<Color>> + Send + Sync + 'static,
+    ) -> Self {
+        self.text_color_option = Some(boxed_sync(text_color_option));
+        self
+    }
+
+    pub fn with_text_signal(
+        mut self,
+        f: Box<dyn FnMut(TextInput, BoxSignal<'static, String>) -> TextInput>,
+    ) -> Self {
+        self.with_text_signal.push(f);
+        self
+    }
+}
+
+impl<
         T: Send + Sync + PartialEq + Reflect + Clone + Debug,
         F: Fn(T) -> String + Send + Sync + Clone + 'static,
     > GlobalEventAware for TextInputField<T, F>
@@ -4398,6 +5691,20 @@ where
     T: Send + Sync + PartialEq + Reflect + Clone + Debug,
     F: Fn(T) -> String + Send + Sync + 'static,
 {
+    base_text_input_with_text_signal(
+        value.signal_cloned().map(formatter),
+        hovered,
+        focused,
+        text_input_option,
+    )
+}
+
+fn base_text_input_with_text_signal(
+    text_signal: impl Signal<Item = String> + Send + 'static,
+    hovered: Mutable<bool>,
+    focused: Mutable<bool>,
+    text_input_option: Option<TextInput>,
+) -> TextInput {
     let background_color = GLOBAL_PRIMARY_BACKGROUND_COLOR.clone();
     let font_size = GLOBAL_FONT_SIZE.clone();
     let unhighlighted_color = GLOBAL_UNHIGHLIGHTED_COLOR.clone();
@@ -4417,7 +5724,7 @@ where
                 .map(Val::Px),
         )
         .hovered_sync(hovered.clone())
-        .text_signal(value.signal_cloned().map(formatter))
+        .text_signal(text_signal)
         .focus_signal(focused.signal().dedupe())
         .focused_sync(focused.clone())
         .text_position(CosmicTextAlign::Center { padding: 0 })
@@ -4460,9 +5767,15 @@ impl<
             .signal_cloned()
             .map(self.formatter.clone())
             .broadcast();
-        base_text_input(
-            value.clone(),
-            self.formatter,
+        let refresh = self.refresh.unwrap_or_default();
+        let formatter = self.formatter;
+        base_text_input_with_text_signal(
+            map_ref! {
+                let value = value.signal_cloned(),
+                let _refresh = refresh.signal() => {
+                    formatter(value.clone())
+                }
+            },
             hovered.clone(),
             focused.clone(),
             Some(self.el),
@@ -4553,6 +5866,10 @@ pub trait NumericFieldable: 'static {
     const STEP: Self::T;
 
     fn from_f32(x: f32) -> Self::T;
+
+    fn to_f64(x: Self::T) -> f64;
+
+    fn from_f64(x: f64) -> Self::T;
 }
 
 macro_rules! impl_numeric_fieldable {
@@ -4565,6 +5882,18 @@ macro_rules! impl_numeric_fieldable {
             fn from_f32(x: f32) -> Self::T {
                 x as Self::T
             }
+
+            fn to_f64(x: Self::T) -> f64 {
+                x as f64
+            }
+
+            fn from_f64(x: f64) -> Self::T {
+                if $is_integral {
+                    x.round() as Self::T
+                } else {
+                    x as Self::T
+                }
+            }
         }
     };
 }
@@ -4585,6 +5914,169 @@ impl_numeric_fieldable!(f32, 0.1, false);
 impl_numeric_fieldable!(f64, 0.1, false);
 // impl_numeric_fieldable!(std::num::NonZeroU8, 1);  // TODO
 
+macro_rules! numeric_reflect_conversions {
+    ($($type:ty),*) => {
+        fn numeric_to_f64(reflect: &dyn PartialReflect) -> Option<f64> {
+            $(
+                if let Some(&x) = reflect.try_downcast_ref::<$type>() {
+                    return Some(<$type as NumericFieldable>::to_f64(x));
+                }
+            )*
+            None
+        }
+
+        fn numeric_from_f64(like: &dyn PartialReflect, x: f64) -> Option<Box<dyn PartialReflect>> {
+            $(
+                if like.try_downcast_ref::<$type>().is_some() {
+                    return Some(Box::new(<$type as NumericFieldable>::from_f64(x)));
+                }
+            )*
+            None
+        }
+    };
+}
+
+numeric_reflect_conversions!(
+    isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128, f32, f64
+);
+
+fn constrain_numeric<T: NumericFieldable>(
+    x: T::T,
+    field_attributes_option: Option<&FieldAttributes>,
+) -> T::T {
+    match field_attributes_option.filter(|field_attributes| field_attributes.constrains_numeric()) {
+        Some(field_attributes) => T::from_f64(field_attributes.constrain(T::to_f64(x))),
+        None => x,
+    }
+}
+
+// plain parsing first so large integers don't lose precision in the f64 roundtrip
+fn parse_numeric<T: NumericFieldable>(text: &str, current: T::T) -> Result<T::T, String> {
+    if let Ok(x) = text.trim().parse::<T::T>() {
+        return Ok(x);
+    }
+    let x = expression::evaluate_relative(text, T::to_f64(current)).map_err(|e| e.to_string())?;
+    if T::IS_INTEGRAL {
+        let x = x.round();
+        // the larger integer maxima round up to the next power of two as f64s, which itself overflows, so the upper bound is exclusive of the maximum plus one
+        if x < T::to_f64(T::T::min_value()) || x >= T::to_f64(T::T::max_value()) + 1. {
+            return Err(format!("{x} overflows {}", T::T::short_type_path()));
+        }
+        Ok(T::from_f64(x))
+    } else {
+        let x = T::from_f64(x);
+        if !T::to_f64(x).is_finite() {
+            return Err(format!("result overflows {}", T::T::short_type_path()));
+        }
+        Ok(x)
+    }
+}
+
+const SLIDER_WIDTH: f32 = 100.;
+
+fn slider_value(
+    like: &dyn PartialReflect,
+    rect: Rect,
+    x: f32,
+    range: &RangeInclusive<f64>,
+    field_attributes_option: Option<&FieldAttributes>,
+) -> Option<Box<dyn PartialReflect>> {
+    let fraction = ((x - rect.min.x) / rect.width().max(1.)).clamp(0., 1.) as f64;
+    let mut new = range.start() + fraction * (range.end() - range.start());
+    if let Some(field_attributes) = field_attributes_option {
+        new = field_attributes.constrain(new);
+    }
+    numeric_from_f64(like, new)
+}
+
+fn numeric_slider(
+    input: FieldElementInput,
+    access_option: Option<Access<'static>>,
+    range: RangeInclusive<f64>,
+) -> impl Element {
+    let fraction = Mutable::new(0.);
+    let dragging = Mutable::new(false);
+    // the latest value of the field, used to convert slider positions back into the field's type
+    let value: Mutable<Option<Box<dyn PartialReflect>>> = Mutable::new(None);
+    let font_size = GLOBAL_FONT_SIZE.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_radius = GLOBAL_BORDER_RADIUS.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let unhighlighted_color = GLOBAL_UNHIGHLIGHTED_COLOR.clone();
+    El::<Node>::new()
+        .width(Val::Px(SLIDER_WIDTH))
+        .height_signal(font_size.signal().map(div(2.)).map(Val::Px))
+        .align(Align::new().center_y())
+        .cursor(CursorIcon::System(SystemCursorIcon::EwResize))
+        .apply(border_width_style(BoxEdge::ALL, border_width.signal()))
+        .apply(border_color_style(border_color.signal()))
+        .apply(border_radius_style(BoxCorner::ALL, border_radius.signal()))
+        .update_raw_el(clone!((fraction, dragging, value, range) move |raw_el| {
+            raw_el
+            .insert(Accessory { target: input.into(), access_option })
+            .insert(PickingBehavior::default())
+            .with_entity(clone!((fraction, value, range) move |mut entity| {
+                let handler = entity.world_scope(move |world| {
+                    register_system(world, move |In(reflect): In<Box<dyn PartialReflect>>| {
+                        if let Some(x) = numeric_to_f64(reflect.as_ref()) {
+                            let (&start, &end) = (range.start(), range.end());
+                            let new = if end > start { ((x - start) / (end - start)).clamp(0., 1.) } else { 0. };
+                            fraction.set_neq(new as f32);
+                        }
+                        value.set(Some(reflect));
+                    })
+                });
+                entity.insert(FieldListener { handler });
+            }))
+            .on_event_with_system_stop_propagation::<Pointer<Down>, _>(clone!((dragging, value, range) move |
+                In((entity, down)): In<(Entity, Pointer<Down>)>,
+                logical_rect: LogicalRect,
+                closest_field_attributes: ClosestFieldAttributes,
+                mut field: TargetField,
+            | {
+                if matches!(down.button, PointerButton::Primary) {
+                    dragging.set_neq(true);
+                    if let Some((like, rect)) = value.lock_ref().as_deref().zip(logical_rect.get(entity)) {
+                        if let Some(new) = slider_value(like, rect, down.pointer_location.position.x, &range, closest_field_attributes.get(entity)) {
+                            field.update(entity, new);
+                        }
+                    }
+                }
+            }))
+            .on_event_with_system_stop_propagation::<Pointer<Drag>, _>(move |
+                In((entity, drag)): In<(Entity, Pointer<Drag>)>,
+                logical_rect: LogicalRect,
+                closest_field_attributes: ClosestFieldAttributes,
+                mut field: TargetField,
+            | {
+                if matches!(drag.button, PointerButton::Primary) {
+                    if let Some((like, rect)) = value.lock_ref().as_deref().zip(logical_rect.get(entity)) {
+                        if let Some(new) = slider_value(like, rect, drag.pointer_location.position.x, &range, closest_field_attributes.get(entity)) {
+                            field.update(entity, new);
+                        }
+                    }
+                }
+            })
+            .on_event_with_system_stop_propagation::<Pointer<Up>, _>(clone!((dragging) move |In((_, up)): In<(Entity, Pointer<Up>)>| {
+                if matches!(up.button, PointerButton::Primary) {
+                    dragging.set_neq(false);
+                }
+            }))
+            .on_event_with_system_stop_propagation::<Pointer<DragEnd>, _>(move |In((_, drag_end)): In<(Entity, Pointer<DragEnd>)>| {
+                if matches!(drag_end.button, PointerButton::Primary) {
+                    dragging.set_neq(false);
+                }
+            })
+        }))
+        .child(
+            El::<Node>::new()
+            .height(Val::Percent(100.))
+            .width_signal(fraction.signal().map(|fraction| Val::Percent(fraction * 100.)))
+            .apply(background_style(map_bool_signal(dragging.signal(), highlighted_color, unhighlighted_color)))
+        )
+}
+
 const INITIAL_NUMERIC_FIELD_INPUT_WIDTH: f32 = 35.;
 
 const INPUT_WIDTH_PER_CHAR: f32 = 10.; // TODO: this should be tied to the font size
@@ -4640,6 +6132,9 @@ where
     let highlight = Mutable::new(false);
     let focused = Mutable::new(false);
     let value = Mutable::new(T::T::default());
+    let refresh = Mutable::new(());
+    // relative expressions like `+=10` apply to the value at the time of focusing
+    let focused_value = Mutable::new(None);
     let error_color = GLOBAL_ERROR_COLOR.clone();
     let padding = GLOBAL_PADDING.clone();
     let parse_failure_color = parse_failed
@@ -4657,6 +6152,7 @@ where
     let mut el = TextInputField::new(T::T::default(), basic_numeric_formatter::<T>())
         .hovered_sync(hovered.clone())
         .with_value(value.clone())
+        .with_refresh(refresh.clone())
         .with_focused(focused.clone())
         .with_highlight(highlight.clone())
         .with_border_color_option(parse_failure_color.signal())
@@ -4693,6 +6189,7 @@ where
             .on_event_with_system_stop_propagation::<Pointer<Drag>, _>(move |
                 In((ui_entity, drag)): In<(Entity, Pointer<Drag>)>,
                 drag_initials: Query<&DragInitial<T>>,
+                closest_field_attributes: ClosestFieldAttributes,
                 mut field: TargetField
             | {
                 if matches!(drag.button, PointerButton::Primary) {
@@ -4701,20 +6198,22 @@ where
                     dragging.set_neq(true);
                     if let Ok(&DragInitial(initial)) = drag_initials.get(ui_entity) {
                         let cur = value.get();
+                        let field_attributes_option = closest_field_attributes.get(ui_entity);
                         let new = if !T::IS_INTEGRAL {
                             // TODO: this seems to do nothing around the max values ?
                             initial + T::from_f32(drag.distance.x) * T::STEP
                         } else {
+                            let step = field_attributes_option.and_then(|field_attributes| field_attributes.step).map(|step| T::from_f64(step.max(1.))).unwrap_or(T::STEP);
                             // TODO: wasn't able to figure out how to integrate the distance into integral values :'(
                             if drag.delta.x > 0. {
-                                if cur <= T::T::max_value() - T::STEP {
-                                    cur + T::STEP
+                                if cur <= T::T::max_value() - step {
+                                    cur + step
                                 } else {
                                     return
                                 }
                             } else if drag.delta.x < 0. {
-                                if cur >= T::T::min_value() + T::STEP {
-                                    cur - T::STEP
+                                if cur >= T::T::min_value() + step {
+                                    cur - step
                                 } else {
                                     return
                                 }
@@ -4722,6 +6221,7 @@ where
                                 return
                             }
                         };
+                        let new = constrain_numeric::<T>(new, field_attributes_option);
                         field.update(ui_entity, new.clone_value());
                     }
                 }
@@ -4775,27 +6275,26 @@ where
         .text_position_signal(padding.signal().map(|padding| CosmicTextAlign::Left {
             padding: padding.round() as i32,
         }))
-        .on_focused_change(clone!((value, parse_failed) move |focused| {
-            if !focused {
-                let mut lock = parse_failed.lock_mut();
-                if lock.is_some() {
-                    value.lock_mut().deref_mut();  // resurface valid value
-                    *lock = None;
-                }
+        .on_focused_change(clone!((value, parse_failed, focused_value) move |focused| {
+            focused_value.set(focused.then(|| value.get()));
+            if !focused && parse_failed.take().is_some() {
+                refresh.set(());
             }
         }))
-        .on_change_with_system(clone!((parse_failed) move |
+        .on_change_with_system(clone!((parse_failed, value) move |
             In((ui_entity, text)): In<(Entity, String)>,
+            closest_field_attributes: ClosestFieldAttributes,
             mut field: TargetField
         | {
-            let result = text.parse::<T::T>();
-            match result {
+            let current = focused_value.get().unwrap_or_else(|| value.get());
+            match parse_numeric::<T>(&text, current) {
                 Ok(new) => {
                     parse_failed.set(None);
+                    let new = constrain_numeric::<T>(new, closest_field_attributes.get(ui_entity));
                     field.update(ui_entity, new.clone_value());
                 }
                 Err(e) => {
-                    parse_failed.set(Some(format!("{:?}", e)));
+                    parse_failed.set(Some(e));
                 }
             }
         }));
@@ -4804,44 +6303,1534 @@ where
 
 const INITIAL_STRING_FIELD_INPUT_WIDTH: f32 = 200.;
 const STRING_FIELD_GROW_THRESHOLD: usize = 16;
+const MULTILINE_STRING_FIELD_WIDTH: f32 = 300.;
+const MULTILINE_STRING_FIELD_MAX_LINES: usize = 16;
+const LINE_HEIGHT: f32 = 1.2;
+
+// enter inserts a newline instead of unfocusing, ctrl+enter commits
+#[derive(Component, Default)]
+struct MultilineTextInput;
+
+fn line_count(text: &str) -> usize {
+    text.split('\n').count()
+}
 
 pub fn string_field<
     T: PartialReflect + From<String> + Into<String> + Default + PartialEq + Reflect + Clone + Debug,
 >() -> impl Element {
     let padding = GLOBAL_PADDING.clone();
+    let font_size = GLOBAL_FONT_SIZE.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let value = Mutable::new(T::default());
+    let multiline_attribute = Mutable::new(false);
+    let multiline = signal::or(
+        multiline_attribute.signal(),
+        value.signal_ref(|value| value.clone().into().contains('\n')),
+    )
+    .dedupe()
+    .broadcast();
+    let refresh = Mutable::new(());
+    // uncommitted multiline text
+    let draft: Mutable<Option<String>> = Mutable::new(None);
+    let lines = map_ref! {
+        let draft_lines = draft.signal_ref(|draft| draft.as_deref().map(line_count)),
+        let &value_lines = value.signal_ref(|value| line_count(&value.clone().into())) => {
+            draft_lines.unwrap_or(value_lines)
+        }
+    };
     TextInputField::new(T::default(), Into::into)
+        .with_value(value.clone())
+        .with_refresh(refresh.clone())
         .cursor(CursorIcon::System(SystemCursorIcon::Text))
         // TODO: without this initial static value, width snaps from 100% due to signal runtime lag
         .width(Val::Px(INITIAL_STRING_FIELD_INPUT_WIDTH))
-        .with_text_signal(Box::new(|self_, text_signal| {
+        .with_text_signal(Box::new(clone!((multiline) move |self_, text_signal| {
             self_.width_signal(
-                text_signal
-                    .map(|text| text.len())
-                    .map(|len| {
-                        INITIAL_STRING_FIELD_INPUT_WIDTH
-                            + if len > STRING_FIELD_GROW_THRESHOLD {
-                                (len - STRING_FIELD_GROW_THRESHOLD) as f32 * INPUT_WIDTH_PER_CHAR
-                            } else {
-                                0.
-                            }
-                    })
-                    .map(Val::Px),
+                map_ref! {
+                    let &multiline = multiline.signal(),
+                    let len = text_signal.map(|text| text.len()) => {
+                        if multiline {
+                            MULTILINE_STRING_FIELD_WIDTH
+                        } else {
+                            INITIAL_STRING_FIELD_INPUT_WIDTH
+                                + if *len > STRING_FIELD_GROW_THRESHOLD {
+                                    (len - STRING_FIELD_GROW_THRESHOLD) as f32 * INPUT_WIDTH_PER_CHAR
+                                } else {
+                                    0.
+                                }
+                        }
+                    }
+                }
+                .map(Val::Px),
             )
-        }))
+        })))
         .into_el()
-        .mode(CosmicWrap::InfiniteLine)
-        // TODO: remove for multiline
-        .max_lines(MaxLines(1))
-        .text_position_signal(padding.signal().map(|padding| CosmicTextAlign::Left {
-            padding: padding.round() as i32,
-        }))
+        .update_raw_el(clone!((multiline, multiline_attribute) move |raw_el| {
+            raw_el
+            .component_signal::<MultilineTextInput, _>(multiline.signal().map_true(default))
+            .on_spawn_with_system(move |In(entity): In<Entity>, closest_field_attributes: ClosestFieldAttributes| {
+                if closest_field_attributes.get(entity).is_some_and(|field_attributes| field_attributes.multiline) {
+                    multiline_attribute.set_neq(true);
+                }
+            })
+        }))
+        .line_height_signal(font_size.signal().map(mul(LINE_HEIGHT)))
+        .mode_signal(multiline.signal().map_bool(|| CosmicWrap::Wrap, || CosmicWrap::InfiniteLine))
+        .max_lines_signal(multiline.signal().map_bool(|| MaxLines(0), || MaxLines(1)))
+        .height_signal(
+            map_ref! {
+                let &multiline = multiline.signal(),
+                let &lines = lines,
+                let &font_size = font_size.signal(),
+                let &border_width = border_width.signal(),
+                let &padding = padding.signal() => {
+                    let lines = if multiline { lines.clamp(1, MULTILINE_STRING_FIELD_MAX_LINES) } else { 1 };
+                    // TODO: where did this 3. come from ?
+                    font_size * LINE_HEIGHT * (lines - 1) as f32 + font_size + border_width * 4. + padding + 3.
+                }
+            }
+            .map(Val::Px),
+        )
+        .text_position_signal(map_ref! {
+            let &multiline = multiline.signal(),
+            let &padding = padding.signal() => {
+                let padding = padding.round() as i32;
+                if multiline {
+                    CosmicTextAlign::TopLeft { padding }
+                } else {
+                    CosmicTextAlign::Left { padding }
+                }
+            }
+        })
+        .on_focused_change(clone!((draft) move |focused| {
+            // uncommitted multiline edits are discarded on unfocus
+            if !focused && draft.take().is_some() {
+                refresh.set(());
+            }
+        }))
         .on_change_with_system(
-            move |In((ui_entity, text)): In<(Entity, String)>, mut field: TargetField| {
-                field.update(ui_entity, T::from(text).clone_value());
+            move |In((ui_entity, text)): In<(Entity, String)>,
+                  keys: Res<ButtonInput<KeyCode>>,
+                  multiline_text_inputs: Query<(), With<MultilineTextInput>>,
+                  mut field: TargetField,
+                  mut commands: Commands| {
+                if multiline_text_inputs.contains(ui_entity) {
+                    if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) && keys.pressed(KeyCode::Enter) {
+                        // the editor has already inserted the newline, so commit the text from before it
+                        let committed = draft.take().unwrap_or_else(|| value.get_cloned().into());
+                        field.update(ui_entity, T::from(committed.clone()).clone_value());
+                        value.set(T::from(committed));
+                        commands.remove_resource::<FocusedTextInput>();
+                    } else {
+                        draft.set(Some(text));
+                    }
+                } else {
+                    field.update(ui_entity, T::from(text).clone_value());
+                }
             },
         )
 }
 
+const RON_FIELD_MAX_LINES: usize = 24;
+
+// the field's value as editable RON, ctrl+enter applies it
+fn ron_field(input: FieldElementInput, access_option: Option<Access<'static>>) -> impl Element {
+    let padding = GLOBAL_PADDING.clone();
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let font_size = GLOBAL_FONT_SIZE.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let error_color = GLOBAL_ERROR_COLOR.clone();
+    let value = Mutable::new(String::new());
+    let refresh = Mutable::new(());
+    // uncommitted text, the synced value doesn't overwrite it
+    let draft: Mutable<Option<String>> = Mutable::new(None);
+    let error: Mutable<Option<String>> = Mutable::new(None);
+    let lines = map_ref! {
+        let draft_lines = draft.signal_ref(|draft| draft.as_deref().map(line_count)),
+        let &value_lines = value.signal_ref(|value| line_count(value)) => {
+            draft_lines.unwrap_or(value_lines)
+        }
+    };
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .apply(padding_style(BoxEdge::VERTICAL, row_gap.signal().map(div(2.))))
+        .apply(padding_style(BoxEdge::HORIZONTAL, padding.signal()))
+        .update_raw_el(clone!((value, draft, error) move |raw_el| {
+            raw_el
+            .insert(Accessory { target: input.into(), access_option })
+            .with_entity(move |mut entity| {
+                let handler = entity.world_scope(|world| {
+                    register_system(world, move |In(reflect): In<Box<dyn PartialReflect>>, type_registry: Res<AppTypeRegistry>| {
+                        if draft.lock_ref().is_some() {
+                            return;
+                        }
+                        let type_registry = type_registry.read();
+                        let result = ron::ser::to_string_pretty(
+                            &TypedReflectSerializer::new(reflect.as_ref(), &type_registry),
+                            ron::ser::PrettyConfig::default(),
+                        );
+                        match result {
+                            Ok(ron) => {
+                                error.set_neq(None);
+                                value.set_neq(ron);
+                            }
+                            Err(e) => error.set_neq(Some(e.to_string())),
+                        }
+                    })
+                });
+                entity.insert(FieldListener { handler });
+            })
+        }))
+        .item(
+            TextInputField::new(String::new(), identity)
+            .with_value(value.clone())
+            .with_refresh(refresh.clone())
+            .cursor(CursorIcon::System(SystemCursorIcon::Text))
+            .width(Val::Percent(100.))
+            .into_el()
+            .update_raw_el(|raw_el| raw_el.insert(MultilineTextInput))
+            .line_height_signal(font_size.signal().map(mul(LINE_HEIGHT)))
+            .mode(CosmicWrap::Wrap)
+            .max_lines(MaxLines(0))
+            .height_signal(
+                map_ref! {
+                    let &lines = lines,
+                    let &font_size = font_size.signal(),
+                    let &border_width = border_width.signal(),
+                    let &padding = padding.signal() => {
+                        let lines = lines.clamp(1, RON_FIELD_MAX_LINES);
+                        // TODO: where did this 3. come from ?
+                        font_size * LINE_HEIGHT * (lines - 1) as f32 + font_size + border_width * 4. + padding + 3.
+                    }
+                }
+                .map(Val::Px),
+            )
+            .text_position_signal(padding.signal().map(|padding| CosmicTextAlign::TopLeft { padding: padding.round() as i32 }))
+            .on_focused_change(clone!((draft, refresh) move |focused| {
+                // uncommitted edits are discarded on unfocus
+                if !focused && draft.take().is_some() {
+                    refresh.set(());
+                }
+            }))
+            .on_change_with_system(clone!((value, draft, error) move |
+                In((ui_entity, text)): In<(Entity, String)>,
+                keys: Res<ButtonInput<KeyCode>>,
+                parents: Query<&Parent>,
+                accessories: Query<&Accessory>,
+                mut field_path_cached: FieldPathCached,
+                closest_field_attributes: ClosestFieldAttributes,
+                edit_settings: Query<&EditSettings>,
+                mut commands: Commands,
+            | {
+                if !(keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) && keys.pressed(KeyCode::Enter)) {
+                    draft.set(Some(text));
+                    return;
+                }
+                if closest_field_attributes.read_only(ui_entity) {
+                    error.set(Some("field is read only".to_string()));
+                    return;
+                }
+                let Some((accessory_entity, &Accessory { target, .. })) = parents.iter_ancestors(ui_entity).find_map(|ancestor| accessories.get(ancestor).ok().map(|accessory| (ancestor, accessory))) else { return };
+                let field_path = field_path_cached.get(accessory_entity);
+                let settings = EditSettings::closest(ui_entity, &parents, &edit_settings);
+                // the editor has already inserted the newline, so apply the text from before it, or the current value if
+                // it wasn't edited
+                let ron = draft.get_cloned().unwrap_or_else(|| value.get_cloned());
+                commands.queue(clone!((draft, error, refresh) move |world: &mut World| {
+                    match apply_ron(world, target, &field_path, &ron, settings) {
+                        Ok(()) => {
+                            error.set(None);
+                            draft.set(None);
+                            refresh.set(());
+                            world.remove_resource::<FocusedTextInput>();
+                        }
+                        Err(e) => error.set(Some(e)),
+                    }
+                }));
+            }))
+        )
+        .item_signal(error.signal_cloned().map_some(move |error| {
+            DynamicText::new()
+            .text(error)
+            .color_signal(error_color.signal())
+        }))
+}
+
+const TIME_CONTROLS_MAX_SPEED: f64 = 4.;
+
+// unpaused for a single frame, paused again after `Time<Virtual>` advances
+#[derive(Resource)]
+struct VirtualTimeStep;
+
+fn pause_after_step(mut time: ResMut<Time<Virtual>>, mut commands: Commands) {
+    time.pause();
+    commands.remove_resource::<VirtualTimeStep>();
+}
+
+fn relative_speed_setter() -> FieldSetter {
+    FieldSetter(Arc::new(|world, value| {
+        if let Some(speed) = numeric_to_f64(value).filter(|speed| speed.is_finite()) {
+            world
+                .resource_mut::<Time<Virtual>>()
+                .set_relative_speed_f64(speed.clamp(0., TIME_CONTROLS_MAX_SPEED));
+        }
+    }))
+}
+
+// pause, step, and relative speed controls for `Time<Virtual>`, shown through reflection and edited through its methods
+fn time_controls_bar() -> impl Element {
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let type_path_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let time_virtual = Mutable::new(None);
+    El::<Node>::new()
+        .align(Align::new().center_y())
+        .apply(padding_style(BoxEdge::HORIZONTAL, padding.signal()))
+        .update_raw_el(clone!((time_virtual) move |raw_el| {
+            raw_el.on_spawn(move |world, _| {
+                time_virtual.set(world.components().resource_id::<Time<Virtual>>());
+            })
+        }))
+        .child_signal(time_virtual.signal().map_some(move |component| {
+            let input = FieldElementInput::Component {
+                owner: ComponentOwnerType::Resource,
+                component,
+            };
+            let step_hovered = Mutable::new(false);
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .apply(padding_style(BoxEdge::VERTICAL, row_gap.signal()))
+                .update_raw_el(move |raw_el| {
+                    raw_el.insert(Accessory {
+                        target: input.into(),
+                        access_option: Some(Access::Field("context".into())),
+                    })
+                })
+                .item(bool_field().update_raw_el(move |raw_el| {
+                    raw_el.insert((
+                        SyncUiAlways,
+                        Accessory {
+                            target: input.into(),
+                            access_option: Some(Access::Field("paused".into())),
+                        },
+                        FieldSetter(Arc::new(|world, value| {
+                            if let Some(&paused) = value.try_downcast_ref::<bool>() {
+                                let mut time = world.resource_mut::<Time<Virtual>>();
+                                if paused {
+                                    time.pause();
+                                } else {
+                                    time.unpause();
+                                }
+                            }
+                        })),
+                    ))
+                }))
+                .item(
+                    DynamicText::new()
+                        .text("paused".to_string())
+                        .color_signal(type_path_color.signal())
+                        .apply(text_no_wrap),
+                )
+                .item(
+                    DynamicText::new()
+                        .text("step".to_string())
+                        .color_signal(map_bool_signal(
+                            step_hovered.signal(),
+                            highlighted_color.clone(),
+                            type_path_color.clone(),
+                        ))
+                        .hovered_sync(step_hovered)
+                        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                        .update_raw_el(|raw_el| {
+                            raw_el
+                                .insert(PickingBehavior::default())
+                                .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                                    |In((_, click)): In<(Entity, Pointer<Click>)>,
+                                     mut time: ResMut<Time<Virtual>>,
+                                     mut commands: Commands| {
+                                        if matches!(click.button, PointerButton::Primary)
+                                            && time.is_paused()
+                                        {
+                                            time.unpause();
+                                            commands.insert_resource(VirtualTimeStep);
+                                        }
+                                    },
+                                )
+                        })
+                        .apply(text_no_wrap),
+                )
+                .item(
+                    DynamicText::new()
+                        .text("speed".to_string())
+                        .color_signal(type_path_color.signal())
+                        .apply(text_no_wrap),
+                )
+                .item(
+                    numeric_field::<f64>()
+                        .apply(basic_numeric_field_width::<f64>)
+                        .update_raw_el(move |raw_el| {
+                            raw_el.insert((
+                                SyncUiAlways,
+                                Accessory {
+                                    target: input.into(),
+                                    access_option: Some(Access::Field("relative_speed".into())),
+                                },
+                                relative_speed_setter(),
+                            ))
+                        }),
+                )
+                .item(
+                    numeric_slider(
+                        input,
+                        Some(Access::Field("relative_speed".into())),
+                        0.0..=TIME_CONTROLS_MAX_SPEED,
+                    )
+                    .update_raw_el(|raw_el| raw_el.insert((SyncUiAlways, relative_speed_setter()))),
+                )
+        }))
+}
+
+// clickable text that edits the `Stepping` resource, inserting it if missing
+fn stepping_button(
+    text: String,
+    active: impl Signal<Item = bool> + Send + 'static,
+    f: impl Fn(&mut Stepping) + Clone + Send + Sync + 'static,
+) -> DynamicText {
+    let hovered = Mutable::new(false);
+    DynamicText::new()
+        .text(text)
+        .color_signal(map_bool_signal(
+            signal::or(hovered.signal(), active),
+            GLOBAL_HIGHLIGHTED_COLOR.clone(),
+            GLOBAL_TERTIARY_BACKGROUND_COLOR.clone(),
+        ))
+        .hovered_sync(hovered)
+        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+        .update_raw_el(move |raw_el| {
+            raw_el
+                .insert(PickingBehavior::default())
+                .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                    move |In((_, click)): In<(Entity, Pointer<Click>)>, mut commands: Commands| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            commands.queue(clone!((f) move |world: &mut World| {
+                                f(&mut world.get_resource_or_insert_with(Stepping::new))
+                            }));
+                        }
+                    },
+                )
+        })
+        .apply(text_no_wrap)
+}
+
+fn system_detail(text: String) -> DynamicText {
+    DynamicText::new()
+        .text(text)
+        .color_signal(GLOBAL_TERTIARY_BACKGROUND_COLOR.signal())
+        .apply(text_no_wrap)
+}
+
+fn system_row(
+    label: InternedScheduleLabel,
+    schedule_stepping: Mutable<bool>,
+    data: SystemData,
+) -> impl Element {
+    let SystemData {
+        node,
+        name,
+        sets,
+        conditions,
+        ambiguities,
+        last_run,
+        cursor,
+        behavior,
+        expanded,
+    } = data;
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let unhighlighted_color = GLOBAL_UNHIGHLIGHTED_COLOR.clone();
+    let hovered = Mutable::new(false);
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .item_signal(cursor.signal().dedupe().map_true(clone!((highlighted_color) move || {
+                    DynamicText::new()
+                        .text(">".to_string())
+                        .color_signal(highlighted_color.signal())
+                        .apply(text_no_wrap)
+                })))
+                .item(
+                    DynamicText::new()
+                        .text(ShortName(&name).to_string())
+                        .color_signal(map_bool_signal(
+                            signal::or(hovered.signal(), cursor.signal()),
+                            highlighted_color,
+                            unhighlighted_color,
+                        ))
+                        .hovered_sync(hovered)
+                        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                        .update_raw_el(clone!((expanded) move |raw_el| {
+                            raw_el
+                                .insert(PickingBehavior::default())
+                                .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                                    move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                                        if matches!(click.button, PointerButton::Primary) {
+                                            flip(&expanded)
+                                        }
+                                    },
+                                )
+                        }))
+                        .apply(text_no_wrap),
+                )
+                .item(
+                    DynamicText::new()
+                        .text_signal(last_run.signal().map(|last_run| match last_run {
+                            Some(ticks) => format!("ran -{ticks}"),
+                            None => "not initialized".to_string(),
+                        }))
+                        .color_signal(GLOBAL_TERTIARY_BACKGROUND_COLOR.signal())
+                        .apply(text_no_wrap),
+                )
+                .item_signal(schedule_stepping.signal().dedupe().map_true(clone!((column_gap) move || {
+                    Row::<Node>::new()
+                        .apply(row_style(column_gap.signal()))
+                        .items(SteppingBehavior::iter().map(clone!((behavior) move |option| {
+                            stepping_button(
+                                option.to_string(),
+                                behavior.signal().map(move |cur| cur == Some(option)),
+                                clone!((behavior) move |stepping: &mut Stepping| {
+                                    if behavior.get() == Some(option) {
+                                        stepping.clear_node(label, node);
+                                        behavior.set(None);
+                                    } else {
+                                        match option {
+                                            SteppingBehavior::Always => stepping.always_run_node(label, node),
+                                            SteppingBehavior::Never => stepping.never_run_node(label, node),
+                                            SteppingBehavior::Break => stepping.set_breakpoint_node(label, node),
+                                        };
+                                        behavior.set(Some(option));
+                                    }
+                                }),
+                            )
+                        })))
+                }))),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            let mut details = vec![];
+            if !sets.is_empty() {
+                details.push(system_detail(format!("in {}", sets.join(", "))));
+            }
+            if !conditions.is_empty() {
+                details.push(system_detail(format!("if {}", conditions.join(", "))));
+            }
+            details.extend(ambiguities.as_slice().iter().map(|ambiguity| system_detail(format!("ambiguous with {ambiguity}"))));
+            if details.is_empty() {
+                details.push(system_detail("no sets, conditions, or ambiguities".to_string()));
+            }
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(border_width.signal(), border_color.signal(), padding.signal()))
+                .items(details)
+        }))
+}
+
+fn schedule_element(data: ScheduleData) -> impl Element {
+    let ScheduleData {
+        label,
+        stepping,
+        systems,
+        ..
+    } = data;
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let hovered = Mutable::new(false);
+    let expanded = Mutable::new(false);
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .update_raw_el(clone!((expanded) move |raw_el| {
+            raw_el
+                .insert(HeaderData { pinned: Mutable::new(false), expanded: expanded.clone() })
+                .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
+                .apply(listen_to_expanded_component(expanded))
+        }))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .hovered_sync(hovered.clone())
+                .item(
+                    HighlightableText::new()
+                        .highlighted_signal(hovered.signal())
+                        .with_text(|text| {
+                            text.text_signal(systems.signal_vec_cloned().len().map(move |len| format!("{label:?} ({len})")))
+                                .apply(text_no_wrap)
+                        })
+                        .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                        .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal()),
+                )
+                .item_signal(signal::or(hovered.signal(), stepping.signal()).dedupe().map_true(clone!((stepping, systems) move || {
+                    stepping_button(
+                        "step".to_string(),
+                        stepping.signal(),
+                        clone!((stepping, systems) move |steppings: &mut Stepping| {
+                            if stepping.get() {
+                                // removing a schedule also clears its systems' behaviors
+                                steppings.remove_schedule(label);
+                                stepping.set(false);
+                                for system in systems.lock_ref().iter() {
+                                    system.behavior.set(None);
+                                }
+                                if SCHEDULES.lock_ref().values().all(|data| !data.stepping.get()) {
+                                    steppings.disable();
+                                }
+                            } else {
+                                steppings.add_schedule(label).enable();
+                                stepping.set(true);
+                            }
+                        }),
+                    )
+                })))
+                .apply(header_wrapper(
+                    hovered.clone(),
+                    clone!((expanded) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            flip(&expanded)
+                        }
+                    }),
+                    row_gap,
+                    GLOBAL_PRIMARY_BACKGROUND_COLOR.clone(),
+                    GLOBAL_SECONDARY_BACKGROUND_COLOR.clone(),
+                    padding.clone(),
+                    Mutable::new(false),
+                ))
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer)),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(border_width.signal(), map_bool_signal(hovered.signal(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
+                .items_signal_vec(systems.signal_vec_cloned().map(clone!((stepping) move |system| system_row(label, stepping.clone(), system))))
+        }))
+}
+
+// every `Schedule` and its systems in execution order, with `Stepping` controls
+fn schedules_root(schedules: MutableBTreeMap<String, ScheduleData>) -> Column<Node> {
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let hovered = Mutable::new(false);
+    let expanded = Mutable::new(false);
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .update_raw_el(clone!((expanded) move |raw_el| {
+            raw_el
+                .insert(SyncSchedules)
+                .insert(HeaderData { pinned: Mutable::new(false), expanded: expanded.clone() })
+                .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
+                .apply(listen_to_expanded_component(expanded))
+        }))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .hovered_sync(hovered.clone())
+                .item(
+                    HighlightableText::new()
+                        .highlighted_signal(hovered.signal())
+                        .with_text(|text| {
+                            text.text_signal(schedules.entries_cloned().len().map(|len| format!("schedules ({len})")))
+                                .font_size_signal(GLOBAL_FONT_SIZE.signal())
+                                .apply(text_no_wrap)
+                        })
+                        .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                        .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal()),
+                )
+                .item_signal(STEPPING_ENABLED.signal().dedupe().map_true(clone!((column_gap) move || {
+                    Row::<Node>::new()
+                        .apply(row_style(column_gap.signal()))
+                        .item(stepping_button("next".to_string(), signal::always(false), |stepping: &mut Stepping| {
+                            stepping.step_frame();
+                        }))
+                        .item(stepping_button("continue".to_string(), signal::always(false), |stepping: &mut Stepping| {
+                            stepping.continue_frame();
+                        }))
+                })))
+                .apply(header_wrapper(
+                    hovered.clone(),
+                    clone!((expanded) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            flip(&expanded)
+                        }
+                    }),
+                    row_gap,
+                    GLOBAL_PRIMARY_BACKGROUND_COLOR.clone(),
+                    GLOBAL_SECONDARY_BACKGROUND_COLOR.clone(),
+                    padding.clone(),
+                    Mutable::new(false),
+                ))
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer)),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(border_width.signal(), map_bool_signal(hovered.signal(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
+                .items_signal_vec(schedules.entries_cloned().map(|(_, data)| schedule_element(data)))
+        }))
+}
+
+// observer entities, which are also listed among the entities unless filtered out with `Inspector::without_component::<Observer>`
+fn observers_root(observers: MutableBTreeMap<Entity, EntityData>) -> Column<Node> {
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let hovered = Mutable::new(false);
+    let expanded = Mutable::new(false);
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .update_raw_el(clone!((expanded) move |raw_el| {
+            raw_el
+                .insert(SyncObservers)
+                .insert(HeaderData { pinned: Mutable::new(false), expanded: expanded.clone() })
+                .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
+                .apply(listen_to_expanded_component(expanded))
+                .apply(scroll_to_header_on_birth)
+        }))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .hovered_sync(hovered.clone())
+                .item(
+                    HighlightableText::new()
+                        .highlighted_signal(hovered.signal())
+                        .with_text(|text| {
+                            text.text_signal(
+                                observers
+                                    .entries_cloned()
+                                    .len()
+                                    .map(|len| format!("observers ({len})")),
+                            )
+                            .font_size_signal(GLOBAL_FONT_SIZE.signal())
+                            .apply(text_no_wrap)
+                        })
+                        .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                        .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal()),
+                )
+                .apply(header_wrapper(
+                    hovered.clone(),
+                    clone!((expanded) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            flip(&expanded)
+                        }
+                    }),
+                    row_gap,
+                    GLOBAL_PRIMARY_BACKGROUND_COLOR.clone(),
+                    GLOBAL_SECONDARY_BACKGROUND_COLOR.clone(),
+                    padding.clone(),
+                    Mutable::new(false),
+                ))
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer)),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(
+                    border_width.signal(),
+                    map_bool_signal(
+                        hovered.signal(),
+                        tertiary_background_color.clone(),
+                        border_color.clone(),
+                    ),
+                    padding.signal(),
+                ))
+                .items_signal_vec(observers.entries_cloned().map(|(id, data)| {
+                    MultiFieldElement::new(MultiFieldData::Entity { id, data })
+                        .show_name()
+                        .font_size_signal(GLOBAL_FONT_SIZE.signal())
+                        .row_gap_signal(GLOBAL_ROW_GAP.signal())
+                        .column_gap_signal(GLOBAL_COLUMN_GAP.signal())
+                        .primary_background_color_signal(GLOBAL_PRIMARY_BACKGROUND_COLOR.signal())
+                        .secondary_background_color_signal(
+                            GLOBAL_SECONDARY_BACKGROUND_COLOR.signal(),
+                        )
+                        .border_color_signal(GLOBAL_BORDER_COLOR.signal())
+                        .border_width_signal(GLOBAL_BORDER_WIDTH.signal())
+                        .padding_signal(GLOBAL_PADDING.signal())
+                        .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                        .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal())
+                        .into_el()
+                        .width(Val::Percent(100.))
+                }))
+        }))
+}
+
+#[derive(Clone, Copy, Debug, Display, EnumIter, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+enum EcsStatsSort {
+    Entities,
+    Components,
+    Id,
+}
+
+#[derive(Clone, PartialEq)]
+struct ArchetypeStats {
+    id: usize,
+    table: usize,
+    entities: usize,
+    // short name and whether it's stored in a sparse set
+    components: Vec<(String, bool)>,
+}
+
+#[derive(Clone, PartialEq)]
+struct ComponentStats {
+    name: String,
+    sparse: bool,
+    entities: usize,
+    archetypes: usize,
+}
+
+#[derive(Component)]
+struct EcsStatsListener {
+    archetypes: MutableVec<ArchetypeStats>,
+    components: MutableVec<ComponentStats>,
+    tables: Mutable<usize>,
+    // archetype ids and lengths the stats were last computed from
+    signature: Vec<(usize, usize)>,
+}
+
+// entity counts can change every frame, so stats are refreshed at most this often (in seconds)
+const ECS_STATS_INTERVAL: f32 = 0.5;
+
+fn sync_ecs_stats(
+    archetypes: &Archetypes,
+    components: &Components,
+    mut listeners: Query<&mut EcsStatsListener>,
+    time: Res<Time<Real>>,
+    mut last_synced: Local<f32>,
+) {
+    let now = time.elapsed_secs();
+    let due = now - *last_synced >= ECS_STATS_INTERVAL;
+    if !due
+        && listeners
+            .iter()
+            .all(|listener| !listener.signature.is_empty())
+    {
+        return;
+    }
+    if due {
+        *last_synced = now;
+    }
+    let signature = archetypes
+        .iter()
+        .map(|archetype| (archetype.id().index(), archetype.len()))
+        .collect::<Vec<_>>();
+    let mut stats = None;
+    for mut listener in listeners.iter_mut() {
+        if listener.signature == signature {
+            continue;
+        }
+        let (archetype_stats, component_stats, tables) = stats.get_or_insert_with(|| {
+            let mut component_stats = HashMap::<ComponentId, ComponentStats>::new();
+            let mut tables = HashSet::new();
+            let archetype_stats = archetypes
+                .iter()
+                .map(|archetype| {
+                    tables.insert(archetype.table_id().as_usize());
+                    let mut archetype_components = vec![];
+                    for component in archetype.components() {
+                        let Some(info) = components.get_info(component) else {
+                            continue;
+                        };
+                        let sparse = matches!(info.storage_type(), StorageType::SparseSet);
+                        let name = ShortName(info.name()).to_string();
+                        let stats =
+                            component_stats
+                                .entry(component)
+                                .or_insert_with(|| ComponentStats {
+                                    name: name.clone(),
+                                    sparse,
+                                    entities: 0,
+                                    archetypes: 0,
+                                });
+                        stats.entities += archetype.len();
+                        stats.archetypes += 1;
+                        archetype_components.push((name, sparse));
+                    }
+                    archetype_components.sort();
+                    ArchetypeStats {
+                        id: archetype.id().index(),
+                        table: archetype.table_id().as_usize(),
+                        entities: archetype.len(),
+                        components: archetype_components,
+                    }
+                })
+                .collect::<Vec<_>>();
+            (
+                archetype_stats,
+                component_stats.into_values().collect::<Vec<_>>(),
+                tables.len(),
+            )
+        });
+        listener
+            .archetypes
+            .lock_mut()
+            .replace_cloned(archetype_stats.clone());
+        listener
+            .components
+            .lock_mut()
+            .replace_cloned(component_stats.clone());
+        listener.tables.set_neq(*tables);
+        listener.signature = signature.clone();
+    }
+}
+
+fn ecs_stats_line(text: String) -> DynamicText {
+    DynamicText::new()
+        .text(text)
+        .color_signal(GLOBAL_TERTIARY_BACKGROUND_COLOR.signal())
+        .apply(text_no_wrap)
+}
+
+fn ecs_stats_subheader(text: &str) -> DynamicText {
+    DynamicText::new()
+        .text(text.to_string())
+        .color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal())
+        .apply(text_no_wrap)
+}
+
+fn sort_archetype_stats(
+    sort: EcsStatsSort,
+    left: &ArchetypeStats,
+    right: &ArchetypeStats,
+) -> std::cmp::Ordering {
+    match sort {
+        EcsStatsSort::Entities => right.entities.cmp(&left.entities),
+        EcsStatsSort::Components => right.components.len().cmp(&left.components.len()),
+        EcsStatsSort::Id => left.id.cmp(&right.id),
+    }
+    .then(left.id.cmp(&right.id))
+}
+
+fn sort_component_stats(
+    sort: EcsStatsSort,
+    left: &ComponentStats,
+    right: &ComponentStats,
+) -> std::cmp::Ordering {
+    match sort {
+        EcsStatsSort::Entities => right.entities.cmp(&left.entities),
+        // components are spread across archetypes, which is what fragments them
+        EcsStatsSort::Components => right.archetypes.cmp(&left.archetypes),
+        EcsStatsSort::Id => std::cmp::Ordering::Equal,
+    }
+    .then_with(|| left.name.cmp(&right.name))
+}
+
+// archetype, table, and per component counts for spotting archetype fragmentation
+fn ecs_stats_root() -> Column<Node> {
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let hovered = Mutable::new(false);
+    let expanded = Mutable::new(false);
+    let sort = Mutable::new(EcsStatsSort::Entities);
+    let archetypes = MutableVec::new();
+    let components = MutableVec::new();
+    let tables = Mutable::new(0);
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .update_raw_el(clone!((expanded, archetypes, components, tables) move |raw_el| {
+            raw_el
+                .insert(HeaderData { pinned: Mutable::new(false), expanded: expanded.clone() })
+                .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
+                .component_signal::<EcsStatsListener, _>(expanded.signal().dedupe().map_true(move || EcsStatsListener {
+                    archetypes: archetypes.clone(),
+                    components: components.clone(),
+                    tables: tables.clone(),
+                    signature: vec![],
+                }))
+                .apply(listen_to_expanded_component(expanded))
+        }))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .hovered_sync(hovered.clone())
+                .item(
+                    HighlightableText::new()
+                        .highlighted_signal(hovered.signal())
+                        .with_text(|text| {
+                            text.text("ecs stats".to_string())
+                                .font_size_signal(GLOBAL_FONT_SIZE.signal())
+                                .apply(text_no_wrap)
+                        })
+                        .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                        .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal()),
+                )
+                .apply(header_wrapper(
+                    hovered.clone(),
+                    clone!((expanded) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            flip(&expanded)
+                        }
+                    }),
+                    row_gap,
+                    GLOBAL_PRIMARY_BACKGROUND_COLOR.clone(),
+                    GLOBAL_SECONDARY_BACKGROUND_COLOR.clone(),
+                    padding.clone(),
+                    Mutable::new(false),
+                ))
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer)),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(border_width.signal(), map_bool_signal(hovered.signal(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
+                .item(
+                    DynamicText::new()
+                        .text_signal(map_ref! {
+                            let archetypes = archetypes.signal_vec_cloned().len(),
+                            let entities = archetypes.signal_vec_cloned().map(|ArchetypeStats { entities, .. }| entities).sum(),
+                            let tables = tables.signal() =>
+                            format!("{archetypes} archetypes, {entities} entities, {tables} tables")
+                        })
+                        .color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal())
+                        .apply(text_no_wrap),
+                )
+                .item(
+                    Row::<Node>::new()
+                        .apply(row_style(column_gap.signal()))
+                        .item(ecs_stats_line("sort by".to_string()))
+                        .items(EcsStatsSort::iter().map(clone!((sort) move |option| {
+                            let option_hovered = Mutable::new(false);
+                            DynamicText::new()
+                                .text(option.to_string())
+                                .color_signal(map_bool_signal(
+                                    signal::or(option_hovered.signal(), sort.signal().map(move |sort| sort == option)),
+                                    GLOBAL_HIGHLIGHTED_COLOR.clone(),
+                                    GLOBAL_TERTIARY_BACKGROUND_COLOR.clone(),
+                                ))
+                                .hovered_sync(option_hovered)
+                                .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                                .update_raw_el(clone!((sort) move |raw_el| {
+                                    raw_el
+                                        .insert(PickingBehavior::default())
+                                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                                            move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                                                if matches!(click.button, PointerButton::Primary) {
+                                                    sort.set_neq(option);
+                                                }
+                                            },
+                                        )
+                                }))
+                                .apply(text_no_wrap)
+                        }))),
+                )
+                .item(ecs_stats_subheader("archetypes"))
+                .items_signal_vec(sort.signal().switch_signal_vec(clone!((archetypes) move |sort| {
+                    archetypes
+                        .signal_vec_cloned()
+                        .sort_by_cloned(move |left, right| sort_archetype_stats(sort, left, right))
+                        .map(|ArchetypeStats { id, table, entities, components }| {
+                            let components = components
+                                .into_iter()
+                                .map(|(name, sparse)| if sparse { format!("{name} (sparse)") } else { name })
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            Column::<Node>::new()
+                                .item(ecs_stats_line(format!("#{id} table #{table}, {entities} entities")))
+                                .item(
+                                    El::<Node>::new()
+                                        .apply(padding_style([BoxEdge::Left], GLOBAL_PADDING.signal()))
+                                        .child(ecs_stats_line(components))
+                                )
+                        })
+                })))
+                .item(ecs_stats_subheader("components"))
+                .items_signal_vec(sort.signal().switch_signal_vec(clone!((components) move |sort| {
+                    components
+                        .signal_vec_cloned()
+                        .sort_by_cloned(move |left, right| sort_component_stats(sort, left, right))
+                        .map(|ComponentStats { name, sparse, entities, archetypes }| {
+                            ecs_stats_line(format!(
+                                "{name}: {entities} entities in {archetypes} archetypes, {}",
+                                if sparse { "sparse set" } else { "table" }
+                            ))
+                        })
+                })))
+        }))
+}
+
+const NO_EVENT_DEFAULT: &str = "event has no default and can't be composed";
+
+fn events_element(events: ComponentId, data: EventsData) -> impl Element {
+    let EventsData { name, expanded } = data;
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let unhighlighted_color = GLOBAL_UNHIGHLIGHTED_COLOR.clone();
+    let error_color = GLOBAL_ERROR_COLOR.clone();
+    let hovered = Mutable::new(false);
+    let buffered = Mutable::new(vec![]);
+    let sent = Mutable::new(0);
+    let draft = Mutable::new(None);
+    let error = Mutable::new(None);
+    let short_name = ShortName(&name).to_string();
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .update_raw_el(clone!((expanded, buffered, sent) move |raw_el| {
+            raw_el
+                .insert(HeaderData { pinned: Mutable::new(false), expanded: expanded.clone() })
+                .insert(BufferedEventsListener { events, buffered, sent })
+                .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
+                .apply(listen_to_expanded_component(expanded))
+        }))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .hovered_sync(hovered.clone())
+                .item(
+                    HighlightableText::new()
+                        .highlighted_signal(hovered.signal())
+                        .with_text(clone!((short_name, buffered, sent) move |text| {
+                            text.text_signal(map_ref! {
+                                let buffered = buffered.signal_ref(Vec::len),
+                                let sent = sent.signal() =>
+                                format!("{short_name} ({buffered} buffered, {sent} sent)")
+                            })
+                            .apply(text_no_wrap)
+                        }))
+                        .highlighted_color_signal(highlighted_color.signal())
+                        .unhighlighted_color_signal(unhighlighted_color.signal()),
+                )
+                .apply(header_wrapper(
+                    hovered.clone(),
+                    clone!((expanded) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            flip(&expanded)
+                        }
+                    }),
+                    row_gap.clone(),
+                    GLOBAL_PRIMARY_BACKGROUND_COLOR.clone(),
+                    GLOBAL_SECONDARY_BACKGROUND_COLOR.clone(),
+                    padding.clone(),
+                    Mutable::new(false),
+                ))
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer)),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            let draft_expanded = Mutable::new(true);
+            let send_hovered = Mutable::new(false);
+            let reset_hovered = Mutable::new(false);
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(border_width.signal(), map_bool_signal(hovered.signal(), tertiary_background_color.clone(), border_color.clone()), padding.signal()))
+                .update_raw_el(clone!((draft, error) move |raw_el| {
+                    raw_el.on_spawn(move |world, _| {
+                        if world.resource::<EventDrafts>().contains_key(&events) || reset_event_draft(world, events) {
+                            draft.set(Some(events));
+                        } else {
+                            error.set(Some(NO_EVENT_DEFAULT.to_string()));
+                        }
+                    })
+                }))
+                .items_signal_vec(buffered.signal_cloned().to_signal_vec().map(clone!((tertiary_background_color) move |event| {
+                    DynamicText::new()
+                        .text(event)
+                        .color_signal(tertiary_background_color.signal())
+                        .apply(text_no_wrap)
+                })))
+                .item_signal(draft.signal().map_some(clone!((name, row_gap, column_gap, tertiary_background_color, border_width, border_color, padding, highlighted_color, unhighlighted_color, draft_expanded) move |events| {
+                    FieldElement::new(FieldElementInput::EventDraft { events }, FieldType::Field(name.clone()), Mutable::new(Viewability::Viewable))
+                    .row_gap_signal(row_gap.signal())
+                    .column_gap_signal(column_gap.signal())
+                    .type_path_color_signal(tertiary_background_color.signal())
+                    .border_width_signal(border_width.signal())
+                    .border_color_signal(border_color.signal())
+                    .padding_signal(padding.signal())
+                    .highlighted_color_signal(highlighted_color.signal())
+                    .unhighlighted_color_signal(unhighlighted_color.signal())
+                    .expanded_signal(draft_expanded.signal().dedupe())
+                })))
+                .item(
+                    Row::<Node>::new()
+                        .apply(row_style(column_gap.signal()))
+                        .item(
+                            DynamicText::new()
+                                .text("send".to_string())
+                                .color_signal(map_bool_signal(send_hovered.signal(), highlighted_color.clone(), tertiary_background_color.clone()))
+                                .hovered_sync(send_hovered)
+                                .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                                .update_raw_el(clone!((error) move |raw_el| {
+                                    raw_el
+                                        .insert(PickingBehavior::default())
+                                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                                            move |In((_, click)): In<(Entity, Pointer<Click>)>, mut commands: Commands| {
+                                                if matches!(click.button, PointerButton::Primary) {
+                                                    commands.queue(clone!((error) move |world: &mut World| {
+                                                        error.set(send_event_draft(world, events).err());
+                                                    }));
+                                                }
+                                            },
+                                        )
+                                }))
+                                .apply(text_no_wrap),
+                        )
+                        .item(
+                            DynamicText::new()
+                                .text("reset".to_string())
+                                .color_signal(map_bool_signal(reset_hovered.signal(), highlighted_color.clone(), tertiary_background_color.clone()))
+                                .hovered_sync(reset_hovered)
+                                .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+                                .update_raw_el(clone!((draft, error) move |raw_el| {
+                                    raw_el
+                                        .insert(PickingBehavior::default())
+                                        .on_event_with_system_stop_propagation::<Pointer<Click>, _>(
+                                            move |In((_, click)): In<(Entity, Pointer<Click>)>, mut commands: Commands| {
+                                                if matches!(click.button, PointerButton::Primary) {
+                                                    // rebuild the composer in case the structure changed, e.g. enum variants
+                                                    draft.set(None);
+                                                    error.set(None);
+                                                    commands.queue(clone!((draft, error) move |world: &mut World| {
+                                                        if reset_event_draft(world, events) {
+                                                            draft.set(Some(events));
+                                                        } else {
+                                                            error.set(Some(NO_EVENT_DEFAULT.to_string()));
+                                                        }
+                                                    }));
+                                                }
+                                            },
+                                        )
+                                }))
+                                .apply(text_no_wrap),
+                        ),
+                )
+                .item_signal(error.signal_cloned().map_some(clone!((error_color) move |error| {
+                    DynamicText::new()
+                        .text(error)
+                        .color_signal(error_color.signal())
+                })))
+        }))
+}
+
+// registered `Events<T>` resources with their buffered events and a composer for sending new ones
+fn events_root(events: MutableBTreeMap<ComponentId, EventsData>) -> Column<Node> {
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let padding = GLOBAL_PADDING.clone();
+    let border_width = GLOBAL_BORDER_WIDTH.clone();
+    let border_color = GLOBAL_BORDER_COLOR.clone();
+    let tertiary_background_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let hovered = Mutable::new(false);
+    let expanded = Mutable::new(false);
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .update_raw_el(clone!((expanded) move |raw_el| {
+            raw_el
+                .insert(SyncEvents)
+                .insert(HeaderData { pinned: Mutable::new(false), expanded: expanded.clone() })
+                .component_signal::<Expanded, _>(expanded.signal().dedupe().map_true(default))
+                .apply(listen_to_expanded_component(expanded))
+        }))
+        .item(
+            Row::<Node>::new()
+                .apply(row_style(column_gap.signal()))
+                .hovered_sync(hovered.clone())
+                .item(
+                    HighlightableText::new()
+                        .highlighted_signal(hovered.signal())
+                        .with_text(|text| {
+                            text.text_signal(
+                                events
+                                    .entries_cloned()
+                                    .len()
+                                    .map(|len| format!("events ({len})")),
+                            )
+                            .font_size_signal(GLOBAL_FONT_SIZE.signal())
+                            .apply(text_no_wrap)
+                        })
+                        .highlighted_color_signal(GLOBAL_HIGHLIGHTED_COLOR.signal())
+                        .unhighlighted_color_signal(GLOBAL_UNHIGHLIGHTED_COLOR.signal()),
+                )
+                .apply(header_wrapper(
+                    hovered.clone(),
+                    clone!((expanded) move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                        if matches!(click.button, PointerButton::Primary) {
+                            flip(&expanded)
+                        }
+                    }),
+                    row_gap,
+                    GLOBAL_PRIMARY_BACKGROUND_COLOR.clone(),
+                    GLOBAL_SECONDARY_BACKGROUND_COLOR.clone(),
+                    padding.clone(),
+                    Mutable::new(false),
+                ))
+                .cursor(CursorIcon::System(SystemCursorIcon::Pointer)),
+        )
+        .item_signal(expanded.signal().dedupe().map_true(move || {
+            Column::<Node>::new()
+                .width(Val::Percent(100.))
+                .apply(move_style(Move_::Right, padding.signal()))
+                .apply(left_bordered_style(
+                    border_width.signal(),
+                    map_bool_signal(
+                        hovered.signal(),
+                        tertiary_background_color.clone(),
+                        border_color.clone(),
+                    ),
+                    padding.signal(),
+                ))
+                .items_signal_vec(
+                    events
+                        .entries_cloned()
+                        .sort_by_cloned(
+                            |(
+                                _,
+                                EventsData {
+                                    name: left_name, ..
+                                },
+                            ),
+                             (
+                                _,
+                                EventsData {
+                                    name: right_name, ..
+                                },
+                            )| type_path_ord(left_name, right_name),
+                        )
+                        .map(|(events, data)| events_element(events, data)),
+                )
+        }))
+}
+
+const PLOT_SAMPLES: usize = 240;
+const PLOT_HEIGHT: usize = 48;
+// x, y, z, w
+const PLOT_SERIES_COLORS: [Color; 4] = [
+    Color::srgb(0.85, 0.35, 0.35),
+    Color::srgb(0.4, 0.8, 0.4),
+    Color::srgb(0.4, 0.55, 0.9),
+    Color::srgb(0.85, 0.85, 0.85),
+];
+
+// scalars are plotted as a single series, vectors as a series per component
+fn plot_series(type_path: &str) -> Option<&'static [&'static str]> {
+    match type_path {
+        "isize" | "i8" | "i16" | "i32" | "i64" | "i128" | "usize" | "u8" | "u16" | "u32"
+        | "u64" | "u128" | "f32" | "f64" => Some(&[]),
+        "glam::Vec2" | "glam::UVec2" | "glam::IVec2" | "glam::DVec2" => Some(&["x", "y"]),
+        "glam::Vec3" | "glam::Vec3A" | "glam::UVec3" | "glam::IVec3" | "glam::DVec3" => {
+            Some(&["x", "y", "z"])
+        }
+        "glam::Vec4" | "glam::UVec4" | "glam::IVec4" | "glam::DVec4" | "glam::Quat" => {
+            Some(&["x", "y", "z", "w"])
+        }
+        _ => None,
+    }
+}
+
+fn draw_plot(image: &mut Image, series: &[VecDeque<f64>]) {
+    let background = GLOBAL_PRIMARY_BACKGROUND_COLOR
+        .get()
+        .to_srgba()
+        .to_u8_array();
+    for pixel in image.data.chunks_exact_mut(4) {
+        pixel.copy_from_slice(&background);
+    }
+    let (min, max) = series
+        .iter()
+        .flatten()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &x| {
+            (min.min(x), max.max(x))
+        });
+    if min > max {
+        return;
+    }
+    // flat series are drawn through the middle
+    let range = if max - min > f64::EPSILON {
+        max - min
+    } else {
+        1.
+    };
+    let offset = if max - min > f64::EPSILON {
+        min
+    } else {
+        min - 0.5
+    };
+    let to_y = |x: f64| {
+        let normalized = ((x - offset) / range).clamp(0., 1.);
+        ((1. - normalized) * (PLOT_HEIGHT - 1) as f64).round() as usize
+    };
+    for (samples, color) in series.iter().zip(PLOT_SERIES_COLORS) {
+        let color = color.to_srgba().to_u8_array();
+        // newest sample on the right
+        let start = PLOT_SAMPLES - samples.len();
+        let mut previous = None;
+        for (i, &sample) in samples.iter().enumerate() {
+            let y = to_y(sample);
+            let (from, to) = match previous {
+                Some(previous) => (y.min(previous), y.max(previous)),
+                None => (y, y),
+            };
+            for y in from..=to {
+                let i = (y * PLOT_SAMPLES + start + i) * 4;
+                image.data[i..i + 4].copy_from_slice(&color);
+            }
+            previous = Some(y);
+        }
+    }
+}
+
+fn plot_stats(samples: &VecDeque<f64>) -> String {
+    if samples.is_empty() {
+        return String::new();
+    }
+    let (min, max, sum) = samples.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY, 0.),
+        |(min, max, sum), &x| (min.min(x), max.max(x), sum + x),
+    );
+    let avg = sum / samples.len() as f64;
+    format!("min {min:.3} max {max:.3} avg {avg:.3}")
+}
+
+// records the field's value every frame and draws the last `PLOT_SAMPLES` of them, one line per component
+fn plot_field(
+    input: FieldElementInput,
+    access_option: Option<Access<'static>>,
+    components: &'static [&'static str],
+) -> impl Element {
+    let padding = GLOBAL_PADDING.clone();
+    let row_gap = GLOBAL_ROW_GAP.clone();
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let font_size = GLOBAL_FONT_SIZE.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let type_path_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let paused = Mutable::new(false);
+    let image: Mutable<Option<AssetId<Image>>> = Mutable::new(None);
+    let stats = (0..components.len().max(1))
+        .map(|_| Mutable::new(String::new()))
+        .collect::<Vec<_>>();
+    Column::<Node>::new()
+        .width(Val::Percent(100.))
+        .apply(padding_style(BoxEdge::VERTICAL, row_gap.signal().map(div(2.))))
+        .apply(padding_style(BoxEdge::HORIZONTAL, padding.signal()))
+        .update_raw_el(clone!((paused, image, stats) move |raw_el| {
+            raw_el
+            .insert((SyncUiAlways, Accessory { target: input.into(), access_option }))
+            .with_entity(move |mut entity| {
+                let handler = entity.world_scope(|world| {
+                    let mut series = vec![VecDeque::with_capacity(PLOT_SAMPLES); stats.len()];
+                    register_system(world, move |In(reflect): In<Box<dyn PartialReflect>>, mut images: ResMut<Assets<Image>>| {
+                        if paused.get() {
+                            return;
+                        }
+                        let samples = if components.is_empty() {
+                            vec![numeric_to_f64(reflect.as_ref())]
+                        } else {
+                            components
+                                .iter()
+                                .map(|component| match reflect.reflect_ref() {
+                                    ReflectRef::Struct(struct_) => struct_.field(component).and_then(numeric_to_f64),
+                                    _ => None,
+                                })
+                                .collect()
+                        };
+                        for ((samples, sample), stats) in series.iter_mut().zip(samples).zip(stats.as_slice().iter()) {
+                            let Some(sample) = sample else { continue };
+                            if samples.len() == PLOT_SAMPLES {
+                                samples.pop_front();
+                            }
+                            samples.push_back(sample);
+                            stats.set_neq(plot_stats(samples));
+                        }
+                        if let Some(image) = image.get().and_then(|image| images.get_mut(image)) {
+                            draw_plot(image, &series);
+                        }
+                    })
+                });
+                entity.insert(FieldListener { handler });
+            })
+        }))
+        .item(
+            El::<ImageNode>::new()
+            .width(Val::Percent(100.))
+            .height(Val::Px(PLOT_HEIGHT as f32))
+            .update_raw_el(move |raw_el| {
+                raw_el.on_spawn(move |world, entity| {
+                    let plot = Image::new_fill(
+                        Extent3d { width: PLOT_SAMPLES as u32, height: PLOT_HEIGHT as u32, depth_or_array_layers: 1 },
+                        TextureDimension::D2,
+                        &GLOBAL_PRIMARY_BACKGROUND_COLOR.get().to_srgba().to_u8_array(),
+                        TextureFormat::Rgba8UnormSrgb,
+                        RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
+                    );
+                    let handle = world.resource_mut::<Assets<Image>>().add(plot);
+                    image.set(Some(handle.id()));
+                    if let Ok(mut entity) = world.get_entity_mut(entity) {
+                        entity.insert(ImageNode::new(handle));
+                    }
+                })
+            })
+        )
+        .items(components.iter().map(Some).chain(components.is_empty().then_some(None)).zip(stats.into_iter().zip(PLOT_SERIES_COLORS)).map(clone!((column_gap, font_size) move |(component, (stats, color))| {
+            Row::<Node>::new()
+            .apply(row_style(column_gap.signal()))
+            .item(component.map(|component| {
+                DynamicText::new()
+                .text(component.to_string())
+                .font_size_signal(font_size.signal())
+                .color(color)
+                .apply(text_no_wrap)
+            }))
+            .item(
+                DynamicText::new()
+                .text_signal(stats.signal_cloned())
+                .font_size_signal(font_size.signal())
+                .apply(text_no_wrap)
+            )
+        })))
+        .item(
+            DynamicText::new()
+            .text_signal(paused.signal().map_bool(|| "resume", || "pause").map(ToString::to_string))
+            .font_size_signal(font_size.signal())
+            .color_signal(map_bool_signal(paused.signal(), highlighted_color, type_path_color))
+            .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+            .update_raw_el(clone!((paused) move |raw_el| {
+                raw_el
+                .insert(PickingBehavior::default())
+                .on_event_with_system_stop_propagation::<Pointer<Click>, _>(move |In((_, click)): In<(Entity, Pointer<Click>)>| {
+                    if matches!(click.button, PointerButton::Primary) {
+                        flip(&paused);
+                    }
+                })
+            }))
+            .apply(text_no_wrap)
+        )
+}
+
 #[derive(Clone)]
 pub struct FieldListener {
     handler: SystemId<In<Box<dyn PartialReflect>>>,
@@ -4873,37 +7862,131 @@ fn remove_from_field_path_cache(mut world: DeferredWorld, entity: Entity, _: Com
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum AccessoryTarget {
-    Component {
-        owner: ComponentOwnerType,
-        component: ComponentId,
-    },
-    Asset {
-        asset: TypeId,
-        handle: UntypedAssetId,
-    },
-}
-
-#[derive(Component, Clone, Debug)]
-// TODO: not sure if this is semantically correct, we do this just as a convenience to insert SyncOnce to all accessories, but it is removed right after
-#[require(SyncUiOnce)]
-#[component(on_remove = remove_from_field_path_cache)]
-pub struct Accessory {
-    target: AccessoryTarget,
-    access_option: Option<Access<'static>>,
+#[derive(Clone, Copy, Debug)]
+pub enum AccessoryTarget {
+    Component {
+        owner: ComponentOwnerType,
+        component: ComponentId,
+    },
+    Asset {
+        asset: TypeId,
+        handle: UntypedAssetId,
+    },
+    EventDraft {
+        events: ComponentId,
+    },
+}
+
+#[derive(Component, Clone, Debug)]
+// TODO: not sure if this is semantically correct, we do this just as a convenience to insert SyncOnce to all accessories, but it is removed right after
+#[require(SyncUiOnce)]
+#[component(on_remove = remove_from_field_path_cache)]
+pub struct Accessory {
+    target: AccessoryTarget,
+    access_option: Option<Access<'static>>,
+}
+
+/// Entities whose archetype changed since the last frame, collected by observers of every component's
+/// [`OnAdd`] and [`OnRemove`], so syncing entities costs as much as the changes rather than the world.
+#[derive(Resource, Default)]
+pub struct EntityChanges {
+    pending: HashSet<Entity>,
+    changed: HashSet<Entity>,
+    // entities without components, which the observers can't see spawn or despawn
+    empty: HashSet<Entity>,
+    observers: Vec<Entity>,
+    observed_components: usize,
+    // archetypes created since this generation may hold entities that gained a component registered after its observers were spawned
+    archetype_generation: Option<ArchetypeGeneration>,
+    generation: u64,
+}
+
+impl EntityChanges {
+    /// The entities changed this frame, or [`None`] if a syncer last synced at `last_generation` must diff every entity.
+    pub fn changed_since(&self, last_generation: &mut Option<u64>) -> Option<&HashSet<Entity>> {
+        let incremental = last_generation.is_some_and(|last| last + 1 == self.generation);
+        *last_generation = Some(self.generation);
+        incremental.then_some(&self.changed)
+    }
+}
+
+#[derive(Component)]
+pub struct AaloObserver;
+
+fn mark_entity_changed<E: Event>(trigger: Trigger<E>, mut entity_changes: ResMut<EntityChanges>) {
+    entity_changes.pending.insert(trigger.entity());
+}
+
+pub fn track_entity_changes(
+    components: &Components,
+    archetypes: &Archetypes,
+    mut entity_changes: ResMut<EntityChanges>,
+    mut commands: Commands,
+) {
+    let entity_changes = &mut *entity_changes;
+    // components registered since the last run need their own observers
+    let observed = entity_changes.observed_components;
+    if components.len() > observed {
+        let ids = (observed..components.len()).map(ComponentId::new);
+        let mut on_add = Observer::new(mark_entity_changed::<OnAdd>);
+        let mut on_remove = Observer::new(mark_entity_changed::<OnRemove>);
+        for id in ids {
+            on_add = on_add.with_component(id);
+            on_remove = on_remove.with_component(id);
+        }
+        entity_changes
+            .observers
+            .push(commands.spawn((on_add, AaloObserver)).id());
+        entity_changes
+            .observers
+            .push(commands.spawn((on_remove, AaloObserver)).id());
+        entity_changes.observed_components = components.len();
+    }
+    entity_changes.changed = std::mem::take(&mut entity_changes.pending);
+    if let Some(archetype_generation) = entity_changes.archetype_generation {
+        for archetype in archetypes[archetype_generation..].iter() {
+            entity_changes
+                .changed
+                .extend(archetype.entities().iter().map(ArchetypeEntity::id));
+        }
+    }
+    entity_changes.archetype_generation = Some(archetypes.generation());
+    let empty = archetypes
+        .get(ArchetypeId::EMPTY)
+        .into_iter()
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+        .collect::<HashSet<_>>();
+    entity_changes
+        .changed
+        .extend(empty.symmetric_difference(&entity_changes.empty).copied());
+    entity_changes.empty = empty;
+    entity_changes.generation += 1;
+}
+
+// no syncer is left to drain the changes, so stop collecting them until one returns
+fn untrack_entity_changes(mut entity_changes: ResMut<EntityChanges>, mut commands: Commands) {
+    for observer in entity_changes.observers.drain(..) {
+        if let Some(entity) = commands.get_entity(observer) {
+            entity.despawn_recursive();
+        }
+    }
+    *entity_changes = default();
+}
+
+fn entity_changes_consumed() -> impl Condition<()> {
+    any_with_component::<SyncOrphanEntities>
+        .or(any_with_component::<SyncEntities>)
+        .or(any_with_component::<SyncObservers>)
+        .or(any_with_component::<EntityRoot>)
+        .or(any_with_component::<WatchedTarget>)
 }
 
-pub fn sync_entities_helper(
-    entities: &MutableBTreeMap<Entity, EntityData>,
-    new: impl IntoIterator<Item = Entity>,
+fn insert_entities(
+    entities: &mut MutableBTreeMapLockMut<Entity, EntityData>,
+    added: impl IntoIterator<Item = Entity>,
     debug_names: &Query<NameOrEntity>,
-    field_path_cache: &mut ResMut<FieldPathCache>,
 ) {
-    let mut entities = entities.lock_mut();
-    let new = new.into_iter().collect::<HashSet<_>>();
-    let old = entities.keys().copied().collect::<HashSet<_>>();
-    for entity in new.difference(&old).copied() {
+    for entity in added {
         let name_option = debug_names
             .get(entity)
             .ok()
@@ -4917,9 +8000,68 @@ pub fn sync_entities_helper(
             },
         );
     }
-    for entity in old.difference(&new) {
-        entities.remove(entity);
-        field_path_cache.0.remove(entity);
+}
+
+fn remove_entities(
+    entities: &mut MutableBTreeMapLockMut<Entity, EntityData>,
+    removed: impl IntoIterator<Item = Entity>,
+    field_path_cache: &mut ResMut<FieldPathCache>,
+) {
+    for entity in removed {
+        entities.remove(&entity);
+        field_path_cache.0.remove(&entity);
+    }
+}
+
+pub fn sync_entities_helper(
+    entities: &MutableBTreeMap<Entity, EntityData>,
+    new: impl IntoIterator<Item = Entity>,
+    debug_names: &Query<NameOrEntity>,
+    field_path_cache: &mut ResMut<FieldPathCache>,
+) {
+    let mut entities = entities.lock_mut();
+    let new = new.into_iter().collect::<HashSet<_>>();
+    let old = entities.keys().copied().collect::<HashSet<_>>();
+    insert_entities(&mut entities, new.difference(&old).copied(), debug_names);
+    remove_entities(
+        &mut entities,
+        old.difference(&new).copied(),
+        field_path_cache,
+    );
+}
+
+/// Like [`sync_entities_helper`], but only checks the `changed` entities, e.g. from [`EntityChanges::changed_since`].
+pub fn sync_changed_entities_helper<F: QueryFilter>(
+    entities: &MutableBTreeMap<Entity, EntityData>,
+    query: &Query<Entity, F>,
+    changed: &HashSet<Entity>,
+    debug_names: &Query<NameOrEntity>,
+    field_path_cache: &mut ResMut<FieldPathCache>,
+) {
+    let mut entities = entities.lock_mut();
+    let (mut added, mut removed) = (vec![], vec![]);
+    for &entity in changed.iter() {
+        match (query.contains(entity), entities.contains_key(&entity)) {
+            (true, false) => added.push(entity),
+            (false, true) => removed.push(entity),
+            _ => (),
+        }
+    }
+    insert_entities(&mut entities, added, debug_names);
+    remove_entities(&mut entities, removed, field_path_cache);
+}
+
+fn sync_entities_with_changes<F: QueryFilter>(
+    entities: &MutableBTreeMap<Entity, EntityData>,
+    query: &Query<Entity, F>,
+    changed: Option<&HashSet<Entity>>,
+    debug_names: &Query<NameOrEntity>,
+    field_path_cache: &mut ResMut<FieldPathCache>,
+) {
+    if let Some(changed) = changed {
+        sync_changed_entities_helper(entities, query, changed, debug_names, field_path_cache)
+    } else {
+        sync_entities_helper(entities, query, debug_names, field_path_cache)
     }
 }
 
@@ -4931,48 +8073,694 @@ fn sync_orphan_entities(
             Without<Parent>,
             Without<HaalkaOneShotSystem>,
             Without<HaalkaObserver>,
+            Without<AaloObserver>,
             Without<AaloOneShotSystem>,
         ),
     >,
+    entity_changes: Res<EntityChanges>,
+    mut last_generation: Local<Option<u64>>,
     debug_names: Query<NameOrEntity>,
     mut field_path_cache: ResMut<FieldPathCache>,
 ) {
-    sync_entities_helper(
+    sync_entities_with_changes(
         &ORPHAN_ENTITIES,
         &query,
+        entity_changes.changed_since(&mut last_generation),
+        &debug_names,
+        &mut field_path_cache,
+    )
+}
+
+fn sync_entity_query_filters(world: &mut World) {
+    let mut entity_query_filters = world.query::<&EntityQueryFilters>();
+    let entity_query_filters = entity_query_filters
+        .iter(world)
+        .cloned()
+        .collect::<Vec<_>>();
+    world.resource_scope(|world, entity_changes: Mut<EntityChanges>| {
+        for EntityQueryFilters(state) in entity_query_filters {
+            let state = &mut *state.lock().unwrap();
+            let changed = entity_changes.changed_since(&mut state.last_generation);
+            let flipped = match changed {
+                // only entities whose archetype changed can start or stop matching
+                Some(changed) if state.filters.iter().all(|filter| filter.archetypal) => {
+                    let mut flipped = vec![];
+                    for &entity in changed {
+                        let matches = world.entities().contains(entity)
+                            && state
+                                .filters
+                                .iter_mut()
+                                .all(|filter| (filter.matches)(world, entity));
+                        let matched = if matches {
+                            state.matched.insert(entity)
+                        } else {
+                            state.matched.remove(&entity)
+                        };
+                        if matched {
+                            flipped.push(entity);
+                        }
+                        if !world.entities().contains(entity) {
+                            state.rows.remove(&entity);
+                        }
+                    }
+                    flipped
+                }
+                _ => {
+                    let (first, rest) = state.filters.split_first_mut().unwrap();
+                    let mut matched = (first.all)(world);
+                    for filter in rest {
+                        matched.retain(|&entity| (filter.matches)(world, entity));
+                    }
+                    let flipped = matched
+                        .symmetric_difference(&state.matched)
+                        .copied()
+                        .collect();
+                    state.matched = matched;
+                    state
+                        .rows
+                        .retain(|&entity, _| world.entities().contains(entity));
+                    flipped
+                }
+            };
+            for entity in flipped {
+                if let Some(row) = state.rows.get(&entity) {
+                    row.set_neq(state.matched.contains(&entity));
+                }
+            }
+        }
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn sync_entities(
+    query: Query<
+        Entity,
+        (
+            Without<HaalkaOneShotSystem>,
+            Without<HaalkaObserver>,
+            Without<AaloObserver>,
+            Without<AaloOneShotSystem>,
+            Without<InspectorBloodline>,
+        ),
+    >,
+    entity_changes: Res<EntityChanges>,
+    mut last_generation: Local<Option<u64>>,
+    debug_names: Query<NameOrEntity>,
+    mut field_path_cache: ResMut<FieldPathCache>,
+) {
+    sync_entities_with_changes(
+        &ENTITIES,
+        &query,
+        entity_changes.changed_since(&mut last_generation),
+        &debug_names,
+        &mut field_path_cache,
+    )
+}
+
+/// Observer entities, excluding the inspector's own.
+pub static OBSERVERS: Lazy<MutableBTreeMap<Entity, EntityData>> = Lazy::new(default);
+
+#[derive(Component)]
+pub struct SyncObservers;
+
+#[allow(clippy::type_complexity)]
+fn sync_observers(
+    query: Query<
+        Entity,
+        (
+            With<Observer>,
+            Without<HaalkaObserver>,
+            Without<AaloObserver>,
+        ),
+    >,
+    entity_changes: Res<EntityChanges>,
+    mut last_generation: Local<Option<u64>>,
+    debug_names: Query<NameOrEntity>,
+    mut field_path_cache: ResMut<FieldPathCache>,
+) {
+    sync_entities_with_changes(
+        &OBSERVERS,
+        &query,
+        entity_changes.changed_since(&mut last_generation),
         &debug_names,
         &mut field_path_cache,
     )
 }
 
-#[allow(clippy::type_complexity)]
-fn sync_entities(
-    query: Query<
-        Entity,
-        (
-            Without<HaalkaOneShotSystem>,
-            Without<HaalkaObserver>,
-            Without<AaloOneShotSystem>,
-            Without<InspectorBloodline>,
-        ),
-    >,
-    debug_names: Query<NameOrEntity>,
-    mut field_path_cache: ResMut<FieldPathCache>,
+const MAX_VALUE_MATCHES: usize = 50;
+const MAX_VALUE_SEARCH_DEPTH: usize = 16;
+
+#[derive(Clone)]
+struct ValueMatch {
+    target: InspectionTarget,
+    value: String,
+}
+
+// inserted on the inspector while its search has a `value:` term, matches are a snapshot taken when the search changes
+#[derive(Component, Clone)]
+struct ValueSearch {
+    value: String,
+    root: InspectionTargetRoot,
+    entities: MutableBTreeMap<Entity, EntityData>,
+    resources: MutableBTreeMap<ComponentId, FieldData>,
+    assets: MutableBTreeMap<TypeId, AssetData>,
+    matches: MutableVec<ValueMatch>,
+}
+
+fn value_matches(reflect: &dyn PartialReflect, value: &str) -> bool {
+    if let Some(x) = numeric_to_f64(reflect) {
+        return value
+            .parse::<f64>()
+            .is_ok_and(|y| (x - y).abs() <= 1e-6 * y.abs().max(1.));
+    }
+    if let Some(&b) = reflect.try_downcast_ref::<bool>() {
+        return value.parse::<bool>().is_ok_and(|value| value == b);
+    }
+    let value = value.to_lowercase();
+    let contains = |text: &str| text.to_lowercase().contains(&value);
+    if let Some(text) = reflect.try_downcast_ref::<String>() {
+        return contains(text);
+    }
+    if let Some(text) = reflect.try_downcast_ref::<Cow<'static, str>>() {
+        return contains(text);
+    }
+    if let Some(name) = reflect.try_downcast_ref::<Name>() {
+        return contains(name.as_str());
+    }
+    false
+}
+
+// depth first search for fields of `reflect` matching `value`, collecting their paths
+fn find_value_matches(
+    reflect: &dyn PartialReflect,
+    value: &str,
+    path: &mut Vec<Access<'static>>,
+    matches: &mut Vec<(ParsedPath, String)>,
+) {
+    if matches.len() >= MAX_VALUE_MATCHES || path.len() > MAX_VALUE_SEARCH_DEPTH {
+        return;
+    }
+    if value_matches(reflect, value) {
+        let parsed_path = ParsedPath(
+            path.iter()
+                .cloned()
+                .map(|access| OffsetAccess {
+                    access,
+                    offset: None,
+                })
+                .collect(),
+        );
+        matches.push((parsed_path, format!("{reflect:?}")));
+        return;
+    }
+    let mut descend = |access: Access<'static>, field: &dyn PartialReflect| {
+        path.push(access);
+        find_value_matches(field, value, path, matches);
+        path.pop();
+    };
+    match reflect.reflect_ref() {
+        ReflectRef::Struct(struct_) => {
+            for i in 0..struct_.field_len() {
+                if let (Some(name), Some(field)) = (struct_.name_at(i), struct_.field_at(i)) {
+                    descend(Access::Field(name.to_string().into()), field);
+                }
+            }
+        }
+        ReflectRef::TupleStruct(tuple_struct) => {
+            for (i, field) in tuple_struct.iter_fields().enumerate() {
+                descend(Access::TupleIndex(i), field);
+            }
+        }
+        ReflectRef::Tuple(tuple) => {
+            for (i, field) in tuple.iter_fields().enumerate() {
+                descend(Access::TupleIndex(i), field);
+            }
+        }
+        ReflectRef::List(list) => {
+            for (i, item) in list.iter().enumerate() {
+                descend(Access::ListIndex(i), item);
+            }
+        }
+        ReflectRef::Array(array) => {
+            for (i, item) in array.iter().enumerate() {
+                descend(Access::ListIndex(i), item);
+            }
+        }
+        ReflectRef::Enum(enum_) => {
+            for (i, field) in enum_.iter_fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => {
+                        descend(Access::Field(name.to_string().into()), field)
+                    }
+                    VariantField::Tuple(field) => descend(Access::TupleIndex(i), field),
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+fn search_values(world: &mut World) {
+    let mut value_searches = world.query_filtered::<&ValueSearch, Changed<ValueSearch>>();
+    let value_searches = value_searches.iter(world).cloned().collect::<Vec<_>>();
+    for ValueSearch {
+        value,
+        root,
+        entities,
+        resources,
+        assets,
+        matches,
+    } in value_searches
+    {
+        let targets = match root {
+            InspectionTargetRoot::Entity => entities
+                .lock_ref()
+                .keys()
+                .filter_map(|&entity| {
+                    let entity_ref = world.get_entity(entity).ok()?;
+                    Some(
+                        entity_ref
+                            .archetype()
+                            .components()
+                            .map(move |component| AccessoryTarget::Component {
+                                owner: ComponentOwnerType::Entity(entity),
+                                component,
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .flatten()
+                .collect::<Vec<_>>(),
+            InspectionTargetRoot::Resource => resources
+                .lock_ref()
+                .keys()
+                .map(|&component| AccessoryTarget::Component {
+                    owner: ComponentOwnerType::Resource,
+                    component,
+                })
+                .collect(),
+            InspectionTargetRoot::Asset => {
+                let type_registry = world.resource::<AppTypeRegistry>().clone();
+                let type_registry = type_registry.read();
+                assets
+                    .lock_ref()
+                    .keys()
+                    .filter_map(|&asset| {
+                        let reflect_asset = type_registry.get_type_data::<ReflectAsset>(asset)?;
+                        Some(
+                            reflect_asset
+                                .ids(world)
+                                .map(|handle| AccessoryTarget::Asset { asset, handle })
+                                .collect::<Vec<_>>(),
+                        )
+                    })
+                    .flatten()
+                    .collect()
+            }
+        };
+        let mut new = vec![];
+        for target in targets {
+            let mut found = vec![];
+            if let Some(reflect) = reflect_accessory_target(world, target) {
+                find_value_matches(
+                    reflect.as_partial_reflect(),
+                    &value,
+                    &mut vec![],
+                    &mut found,
+                );
+            }
+            for (path, value) in found {
+                if let Some(target) = field_inspection_target(world, target, &path) {
+                    new.push(ValueMatch { target, value });
+                }
+            }
+            if new.len() >= MAX_VALUE_MATCHES {
+                new.truncate(MAX_VALUE_MATCHES);
+                break;
+            }
+        }
+        matches.lock_mut().replace_cloned(new);
+    }
+}
+
+fn inspection_target_link(
+    target: InspectionTarget,
+    text: String,
+    font_size: Mutable<f32>,
+    highlighted_color: Mutable<Color>,
+    unhighlighted_color: Mutable<Color>,
+) -> impl Element {
+    let hovered = Mutable::new(false);
+    El::<Node>::new()
+        .hovered_sync(hovered.clone())
+        .cursor(CursorIcon::System(SystemCursorIcon::Pointer))
+        .on_click_with_system(
+            move |In((entity, click)): In<(Entity, Pointer<Click>)>,
+                  parents: Query<&Parent>,
+                  inspection_target_holders: Query<&InspectionTargetHolder>,
+                  mut commands: Commands| {
+                if matches!(click.button, PointerButton::Primary) {
+                    if let Some(holder) = parents
+                        .iter_ancestors(entity)
+                        .find(|&ancestor| inspection_target_holders.contains(ancestor))
+                    {
+                        if let Some(mut entity) = commands.get_entity(holder) {
+                            entity.try_insert(target.clone());
+                        }
+                    }
+                }
+            },
+        )
+        .child(
+            HighlightableText::new()
+                .highlighted_signal(hovered.signal())
+                .with_text(move |text_el| {
+                    text_el
+                        .text(text)
+                        .font_size_signal(font_size.signal())
+                        .apply(text_no_wrap)
+                })
+                .highlighted_color_signal(highlighted_color.signal())
+                .unhighlighted_color_signal(unhighlighted_color.signal()),
+        )
+}
+
+// fields shown in the inspector's watch section
+#[derive(Component, Clone)]
+struct WatchList(MutableVec<InspectionTarget>);
+
+impl WatchList {
+    fn toggle(&self, target: InspectionTarget) {
+        let mut lock = self.0.lock_mut();
+        if let Some(i) = lock.iter().position(|watched| *watched == target) {
+            lock.remove(i);
+        } else {
+            lock.push_cloned(target);
+        }
+    }
+}
+
+type ResolvedInspectionTarget = (FieldElementInput, String, ParsedPath);
+
+// a watched target, re-resolved as its entity or resource changes so targets watched before their entity exists, or whose entity
+// is respawned, still resolve
+#[derive(Component)]
+struct WatchedTarget {
+    target: InspectionTarget,
+    resolved: Mutable<Option<ResolvedInspectionTarget>>,
+}
+
+fn sync_watched_targets(
+    world: &mut World,
+    mut last_generation: Local<Option<u64>>,
+    mut last_resources: Local<Vec<ComponentId>>,
+) {
+    let mut watched_targets = world.query::<&WatchedTarget>();
+    let watched = watched_targets
+        .iter(world)
+        .map(|WatchedTarget { target, resolved }| (target.clone(), resolved.clone()))
+        .collect::<Vec<_>>();
+    if watched.is_empty() {
+        return;
+    }
+    let resources = world
+        .iter_resources()
+        .map(|(info, _)| info.id())
+        .collect::<Vec<_>>();
+    let resources_changed = *last_resources != resources;
+    *last_resources = resources;
+    world.resource_scope(|world, entity_changes: Mut<EntityChanges>| {
+        let changed = entity_changes.changed_since(&mut last_generation);
+        for (target, resolved) in watched {
+            let stale = match target.root {
+                InspectionTargetRoot::Entity => changed.is_none_or(|changed| {
+                    entity_target_changed(world, &target, resolved.lock_ref().as_ref(), changed)
+                }),
+                InspectionTargetRoot::Resource => resources_changed,
+                InspectionTargetRoot::Asset => match &*resolved.lock_ref() {
+                    Some((FieldElementInput::Asset { asset, handle }, ..)) => {
+                        reflect_asset(world, *asset, *handle).is_none()
+                    }
+                    _ => true,
+                },
+            };
+            if stale {
+                resolved.set_neq(resolve_inspection_target(world, &target));
+            }
+        }
+    });
+}
+
+// whether the changed entities include the one an entity target resolved to or one it could now resolve to
+fn entity_target_changed(
+    world: &World,
+    target: &InspectionTarget,
+    resolved: Option<&ResolvedInspectionTarget>,
+    changed: &HashSet<Entity>,
+) -> bool {
+    if let Some((
+        FieldElementInput::Component {
+            owner: ComponentOwnerType::Entity(entity),
+            ..
+        },
+        ..,
+    )) = resolved
+    {
+        if changed.contains(entity) {
+            return true;
+        }
+    }
+    let Some(InspectionTargetInner::Multi(InspectionTargetMutliField { name, .. })) =
+        &target.target
+    else {
+        return false;
+    };
+    match parse_entity(name) {
+        Some(entity) => changed.contains(&entity),
+        None => changed.iter().any(|&entity| {
+            world
+                .get::<Name>(entity)
+                .is_some_and(|entity_name| entity_name.as_str().eq_ignore_ascii_case(name))
+        }),
+    }
+}
+
+// the inverse of `Entity`'s `Display`, e.g. `5v1`
+fn parse_entity(name: &str) -> Option<Entity> {
+    let (index, generation) = name.split_once('v')?;
+    let bits =
+        u64::from(generation.parse::<u32>().ok()?) << 32 | u64::from(index.parse::<u32>().ok()?);
+    Entity::try_from_bits(bits).ok()
+}
+
+// the field an `InspectionTarget` points to, along with the type path of its root
+fn resolve_inspection_target(
+    world: &mut World,
+    target: &InspectionTarget,
+) -> Option<ResolvedInspectionTarget> {
+    let InspectionTarget {
+        root,
+        target: Some(inner),
+    } = target
+    else {
+        return None;
+    };
+    match (root, inner) {
+        (
+            InspectionTargetRoot::Entity,
+            InspectionTargetInner::Multi(InspectionTargetMutliField {
+                name,
+                field: Some(InspectionTargetField { field, path }),
+            }),
+        ) => {
+            let entity = match parse_entity(name) {
+                Some(entity) => entity,
+                None => world
+                    .query::<(Entity, &Name)>()
+                    .iter(world)
+                    .find(|(_, entity_name)| entity_name.as_str().eq_ignore_ascii_case(name))
+                    .map(|(entity, _)| entity)?,
+            };
+            let entity_ref = world.get_entity(entity).ok()?;
+            let info = entity_ref
+                .archetype()
+                .components()
+                .filter_map(|component| world.components().get_info(component))
+                .find(|info| lax_type_path_match(field, info.name()))?;
+            Some((
+                FieldElementInput::Component {
+                    owner: ComponentOwnerType::Entity(entity_ref.id()),
+                    component: info.id(),
+                },
+                info.name().to_string(),
+                path.clone().unwrap_or(ParsedPath(vec![])),
+            ))
+        }
+        (
+            InspectionTargetRoot::Resource,
+            InspectionTargetInner::Solo(InspectionTargetField { field, path }),
+        ) => {
+            let (info, _) = world
+                .iter_resources()
+                .find(|(info, _)| lax_type_path_match(field, info.name()))?;
+            Some((
+                FieldElementInput::Component {
+                    owner: ComponentOwnerType::Resource,
+                    component: info.id(),
+                },
+                info.name().to_string(),
+                path.clone().unwrap_or(ParsedPath(vec![])),
+            ))
+        }
+        (
+            InspectionTargetRoot::Asset,
+            InspectionTargetInner::Multi(InspectionTargetMutliField {
+                name,
+                field: Some(InspectionTargetField { field, path }),
+            }),
+        ) => {
+            let asset_server = world.get_resource::<AssetServer>()?;
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            let (asset, handle) = type_registry.iter().find_map(|registration| {
+                let reflect_asset = registration.data::<ReflectAsset>()?;
+                if !lax_type_path_match(
+                    name,
+                    &ShortName(registration.type_info().type_path()).to_string(),
+                ) {
+                    return None;
+                }
+                let handle = reflect_asset
+                    .ids(world)
+                    .find(|&handle| handle_name(handle, asset_server) == *field)?;
+                Some((registration.type_id(), handle))
+            })?;
+            Some((
+                FieldElementInput::Asset { asset, handle },
+                field.clone(),
+                path.clone().unwrap_or(ParsedPath(vec![])),
+            ))
+        }
+        _ => None,
+    }
+}
+
+// a field at a path of a component/resource/asset, wrapping it in an accessory for each access preceding its own
+fn watched_field(
+    input: FieldElementInput,
+    type_path: String,
+    path: ParsedPath,
+    style: impl FnOnce(FieldElement) -> FieldElement,
+) -> El<Node> {
+    let Some((OffsetAccess { access, .. }, prefix)) = path.0.split_last() else {
+        return El::<Node>::new()
+            .width(Val::Percent(100.))
+            .child(style(FieldElement::new(
+                input,
+                FieldType::Field(type_path),
+                default(),
+            )));
+    };
+    let mut el = El::<Node>::new()
+        .width(Val::Percent(100.))
+        .child(style(FieldElement::new(
+            input,
+            FieldType::Access(access.clone()),
+            default(),
+        )));
+    for OffsetAccess { access, .. } in prefix.iter().rev() {
+        let access = access.clone();
+        el = El::<Node>::new()
+            .width(Val::Percent(100.))
+            .update_raw_el(move |raw_el| {
+                raw_el.insert(Accessory {
+                    target: input.into(),
+                    access_option: Some(access),
+                })
+            })
+            .child(el);
+    }
+    el
+}
+
+// inserted on the inspector while its search has `with:`, `without:`, or `changed:` terms
+#[derive(Component)]
+struct EntitySearch {
+    query: SearchQuery,
+    entities: MutableBTreeMap<Entity, EntityData>,
+}
+
+fn filter_entities_by_search(
+    entity_searches: Query<&EntitySearch>,
+    world: &World,
+    system_change_tick: SystemChangeTick,
 ) {
-    sync_entities_helper(&ENTITIES, &query, &debug_names, &mut field_path_cache)
+    let components = world.components();
+    let component_ids_matching = |pattern: &str| {
+        components
+            .iter()
+            .filter(|info| type_path_matches(pattern, info.name()))
+            .map(ComponentInfo::id)
+            .collect::<HashSet<_>>()
+    };
+    for EntitySearch { query, entities } in entity_searches.iter() {
+        // resolve each term's components once rather than per entity
+        let terms = query
+            .terms
+            .iter()
+            .filter_map(|term| match term {
+                SearchTerm::With(pattern) => Some((term, component_ids_matching(pattern))),
+                SearchTerm::Without(pattern) => Some((term, component_ids_matching(pattern))),
+                SearchTerm::Changed(pattern) => Some((term, component_ids_matching(pattern))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut name_matcher = NameMatcher::new(query);
+        for (&entity, EntityData { name, filtered, .. }) in entities.lock_ref().iter() {
+            let Ok(entity_ref) = world.get_entity(entity) else {
+                continue;
+            };
+            let archetype = entity_ref.archetype();
+            let matches = terms.iter().all(|(term, component_ids)| match term {
+                SearchTerm::With(_) => component_ids.iter().any(|&id| archetype.contains(id)),
+                SearchTerm::Without(_) => !component_ids.iter().any(|&id| archetype.contains(id)),
+                _ => component_ids.iter().any(|&id| {
+                    entity_ref.get_change_ticks_by_id(id).is_some_and(|ticks| {
+                        ticks.is_changed(
+                            system_change_tick.last_run(),
+                            system_change_tick.this_run(),
+                        )
+                    })
+                }),
+            }) && name_matcher.matches(name.lock_ref().as_deref());
+            filtered.set_neq(!matches);
+        }
+    }
 }
 
 #[allow(clippy::type_complexity)]
 fn sync_components(
     mut entity_roots: Query<
-        (Entity, &mut EntityRoot),
+        (
+            Entity,
+            &mut EntityRoot,
+            Has<SyncComponentsOnce>,
+            Option<Ref<SyncComponents>>,
+        ),
         Or<(With<SyncComponents>, With<SyncComponentsOnce>)>,
     >,
+    entity_changes: Res<EntityChanges>,
+    mut last_generation: Local<Option<u64>>,
     entities: &Entities,
     archetypes: &Archetypes,
     mut commands: Commands,
 ) {
-    for (ui_entity, mut entity_root) in entity_roots.iter_mut() {
+    let changed = entity_changes.changed_since(&mut last_generation);
+    for (ui_entity, mut entity_root, once, sync_components) in entity_roots.iter_mut() {
+        // changes made while not syncing are missed, so newly syncing roots diff regardless
+        let newly_syncing = once || sync_components.is_some_and(|sync| sync.is_added());
+        if !newly_syncing && changed.is_some_and(|changed| !changed.contains(&entity_root.entity)) {
+            continue;
+        }
         if let Some(location) = entities.get(entity_root.entity) {
             if let Some(archetype) = archetypes.get(location.archetype_id) {
                 let new = archetype.components().collect::<HashSet<_>>();
@@ -5080,17 +8868,694 @@ fn sync_visibility(
     }
 }
 
+// lists longer than this only spawn the items within the inspector viewport plus `VIRTUALIZATION_OVERSCAN`
+const VIRTUALIZATION_THRESHOLD: usize = 200;
+const VIRTUALIZATION_OVERSCAN: f32 = 500.;
+// how many frames a list keeps looking for, and then holding open, an item an inspection target is headed to
+const VIRTUALIZATION_REVEAL_FRAMES: u32 = 120;
+
+// the height of a virtualized item, 0 until measured
+#[derive(Component)]
+struct VirtualItemHeight {
+    height: Mutable<f32>,
+    // shared by every item of the list
+    dirty: Arc<AtomicBool>,
+}
+
+trait VirtualWindow: Send + Sync {
+    // `top` and `bottom` are relative to the top of the list
+    fn sync(&self, top: f32, bottom: f32);
+    // keeps the item matching `part` spawned until it's scrolled into view
+    fn reveal(&self, part: ProgressPart);
+}
+
+#[derive(Component)]
+struct VirtualList(Arc<dyn VirtualWindow>);
+
+type VirtualItemMatcher<T> = Box<dyn Fn(&T, &ProgressPart) -> bool + Send + Sync>;
+
+struct VirtualListState<T> {
+    items: Vec<(T, Mutable<f32>)>,
+    // `visible` always mirrors `items[window.0..window.1]`
+    window: (usize, usize),
+    visible: MutableVec<(T, Mutable<f32>)>,
+    top_spacer: Mutable<f32>,
+    bottom_spacer: Mutable<f32>,
+    // `prefix[i]` is the height of the items before `i`
+    prefix: Vec<f32>,
+    heights_dirty: Arc<AtomicBool>,
+    items_dirty: bool,
+    viewport: (f32, f32),
+    // stands in for items that haven't been measured yet
+    estimated_height: f32,
+    matcher: VirtualItemMatcher<T>,
+    revealing: Option<(ProgressPart, u32)>,
+    revealed: Option<(usize, u32)>,
+}
+
+impl<T: Clone> VirtualListState<T> {
+    fn new(
+        estimated_height: f32,
+        matcher: impl Fn(&T, &ProgressPart) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            items: vec![],
+            window: (0, 0),
+            visible: MutableVec::new(),
+            top_spacer: Mutable::new(0.),
+            bottom_spacer: Mutable::new(0.),
+            prefix: vec![0.],
+            heights_dirty: Arc::new(AtomicBool::new(false)),
+            items_dirty: false,
+            viewport: (0., 0.),
+            estimated_height,
+            matcher: Box::new(matcher),
+            revealing: None,
+            revealed: None,
+        }
+    }
+
+    fn set_window(&mut self, start: usize, end: usize) {
+        let (cur_start, cur_end) = self.window;
+        if (start, end) == (cur_start, cur_end) {
+            return;
+        }
+        let mut visible = self.visible.lock_mut();
+        if start >= cur_end || end <= cur_start {
+            visible.replace_cloned(self.items[start..end].to_vec());
+        } else {
+            // back first so the front indices stay valid
+            if end < cur_end {
+                for _ in end..cur_end {
+                    visible.pop();
+                }
+            } else {
+                for item in self.items[cur_end..end].iter() {
+                    visible.push_cloned(item.clone());
+                }
+            }
+            if start > cur_start {
+                for _ in cur_start..start {
+                    visible.remove(0);
+                }
+            } else {
+                for (i, item) in self.items[start..cur_start].iter().enumerate() {
+                    visible.insert_cloned(i, item.clone());
+                }
+            }
+        }
+        self.window = (start, end);
+    }
+
+    fn insert(&mut self, index: usize, item: (T, Mutable<f32>)) {
+        let (start, end) = self.window;
+        self.items.insert(index, item.clone());
+        if let Some((revealed, _)) = &mut self.revealed {
+            if index <= *revealed {
+                *revealed += 1;
+            }
+        }
+        // items appended to a full window wait for the next sync, otherwise a list growing one item at a time spawns every item
+        if index < start || index == end && end - start >= VIRTUALIZATION_THRESHOLD {
+            if index < start {
+                self.window = (start + 1, end + 1);
+            }
+        } else if index <= end {
+            self.visible.lock_mut().insert_cloned(index - start, item);
+            self.window = (start, end + 1);
+            if end + 1 - start > VIRTUALIZATION_THRESHOLD
+                && self.items.len() > VIRTUALIZATION_THRESHOLD
+            {
+                self.set_window(start, start + VIRTUALIZATION_THRESHOLD);
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> (T, Mutable<f32>) {
+        let (start, end) = self.window;
+        let item = self.items.remove(index);
+        if let Some((revealed, _)) = self.revealed {
+            self.revealed = match revealed.cmp(&index) {
+                Ordering::Less => self.revealed,
+                Ordering::Equal => None,
+                Ordering::Greater => self.revealed.map(|(i, frames)| (i - 1, frames)),
+            };
+        }
+        if index < start {
+            self.window = (start - 1, end - 1);
+        } else if index < end {
+            self.visible.lock_mut().remove(index - start);
+            self.window = (start, end - 1);
+        }
+        item
+    }
+
+    fn apply_diff(&mut self, diff: VecDiff<T>) {
+        match diff {
+            VecDiff::Replace { values } => {
+                let (start, end) = self.window;
+                self.items = values
+                    .into_iter()
+                    .map(|value| (value, Mutable::new(0.)))
+                    .collect();
+                self.window = (0, 0);
+                self.revealed = None;
+                self.visible.lock_mut().clear();
+                let len = self.items.len();
+                let start = start.min(len);
+                self.set_window(start, end.min(len).min(start + VIRTUALIZATION_THRESHOLD));
+            }
+            VecDiff::InsertAt { index, value } => self.insert(index, (value, Mutable::new(0.))),
+            VecDiff::Push { value } => self.insert(self.items.len(), (value, Mutable::new(0.))),
+            VecDiff::UpdateAt { index, value } => {
+                self.items[index].0 = value;
+                let (start, end) = self.window;
+                if (start..end).contains(&index) {
+                    self.visible
+                        .lock_mut()
+                        .set_cloned(index - start, self.items[index].clone());
+                }
+            }
+            VecDiff::RemoveAt { index } => {
+                self.remove(index);
+            }
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => {
+                let item = self.remove(old_index);
+                self.insert(new_index, item);
+            }
+            VecDiff::Pop {} => {
+                self.remove(self.items.len() - 1);
+            }
+            VecDiff::Clear {} => {
+                self.items.clear();
+                self.window = (0, 0);
+                self.revealed = None;
+                self.visible.lock_mut().clear();
+            }
+        }
+        self.items_dirty = true;
+        let len = self.items.len();
+        if len <= VIRTUALIZATION_THRESHOLD {
+            self.set_window(0, len);
+        }
+    }
+
+    fn sync_prefix(&mut self) {
+        let (mut measured, mut measured_count) = (0., 0);
+        for (_, height) in self.items.as_slice().iter() {
+            let height = height.get();
+            if height > 0. {
+                measured += height;
+                measured_count += 1;
+            }
+        }
+        if measured_count > 0 {
+            self.estimated_height = measured / measured_count as f32;
+        }
+        let mut y = 0.;
+        self.prefix.clear();
+        self.prefix.push(y);
+        for (_, height) in self.items.as_slice().iter() {
+            let height = height.get();
+            y += if height > 0. {
+                height
+            } else {
+                self.estimated_height
+            };
+            self.prefix.push(y);
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> VirtualWindow for Mutex<VirtualListState<T>> {
+    fn sync(&self, top: f32, bottom: f32) {
+        let mut state = self.lock().unwrap();
+        let state = &mut *state;
+        let len = state.items.len();
+        if len <= VIRTUALIZATION_THRESHOLD {
+            state.set_window(0, len);
+            state.top_spacer.set_neq(0.);
+            state.bottom_spacer.set_neq(0.);
+            state.revealing = None;
+            state.revealed = None;
+            return;
+        }
+        if let Some((part, frames)) = state.revealing.take() {
+            if let Some(i) = state
+                .items
+                .as_slice()
+                .iter()
+                .position(|(item, _)| (state.matcher)(item, &part))
+            {
+                state.revealed = Some((i, VIRTUALIZATION_REVEAL_FRAMES));
+            } else if frames > 0 {
+                state.revealing = Some((part, frames - 1));
+            }
+        }
+        let heights_dirty = state.heights_dirty.swap(false, AtomicOrdering::Relaxed);
+        if !(heights_dirty
+            || state.items_dirty
+            || state.viewport != (top, bottom)
+            || state.revealed.is_some())
+        {
+            return;
+        }
+        if heights_dirty || state.items_dirty {
+            state.sync_prefix();
+            state.items_dirty = false;
+        }
+        state.viewport = (top, bottom);
+        let prefix = &state.prefix;
+        let end = prefix[..len].partition_point(|&y| y < bottom);
+        let start = prefix[1..=len].partition_point(|&y| y <= top).min(end);
+        let (start, end) = match state.revealed {
+            // the item has been scrolled into view or was never scrolled to
+            Some((i, frames)) if (start..end).contains(&i) || frames == 0 => {
+                state.revealed = None;
+                (start, end)
+            }
+            Some((i, frames)) => {
+                state.revealed = Some((i, frames - 1));
+                (i, i + 1)
+            }
+            None => (start, end),
+        };
+        let (top_spacer, bottom_spacer) = (prefix[start], prefix[len] - prefix[end]);
+        state.set_window(start, end);
+        state.top_spacer.set_neq(top_spacer);
+        state.bottom_spacer.set_neq(bottom_spacer);
+    }
+
+    fn reveal(&self, part: ProgressPart) {
+        self.lock().unwrap().revealing = Some((part, VIRTUALIZATION_REVEAL_FRAMES));
+    }
+}
+
+// the next part of the inspection target headed through `entity`, if any
+fn pending_progress_part(
+    entity: Entity,
+    parents: &Query<&Parent>,
+    progresses: &Query<&InspectionTargetProgress>,
+    inspection_targets: &Query<&InspectionTarget>,
+) -> Option<ProgressPart> {
+    for ancestor in parents.iter_ancestors(entity) {
+        if let Ok(InspectionTargetProgress { pending }) = progresses.get(ancestor) {
+            return pending.front().cloned();
+        }
+        if let Ok(InspectionTarget { target, .. }) = inspection_targets.get(ancestor) {
+            return match target.as_ref()? {
+                InspectionTargetInner::Multi(InspectionTargetMutliField { name, .. }) => {
+                    Some(ProgressPart::Field(name.clone()))
+                }
+                InspectionTargetInner::Solo(InspectionTargetField { field, .. }) => {
+                    Some(ProgressPart::Field(field.clone()))
+                }
+            };
+        }
+    }
+    None
+}
+
+// renders `items` into the column such that only those within the inspector viewport plus `VIRTUALIZATION_OVERSCAN` are spawned, with spacers sized from the measured (or estimated) heights of the rest standing in for them; lists of at most `VIRTUALIZATION_THRESHOLD` items are spawned in full
+// `matcher` picks out the item an inspection target's next part refers to, so targets can reach items that aren't spawned
+fn virtualized_items<T, E>(
+    items: impl SignalVec<Item = T> + Send + 'static,
+    matcher: impl Fn(&T, &ProgressPart) -> bool + Send + Sync + 'static,
+    f: impl Fn(T) -> E + Send + 'static,
+) -> impl FnOnce(Column<Node>) -> Column<Node>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Element + RawElWrapper,
+{
+    move |column| {
+        let state =
+            VirtualListState::new(GLOBAL_FONT_SIZE.get() + GLOBAL_PADDING.get() * 2., matcher);
+        let top_spacer = state.top_spacer.clone();
+        let bottom_spacer = state.bottom_spacer.clone();
+        let visible = state.visible.clone();
+        let heights_dirty = state.heights_dirty.clone();
+        let state = Arc::new(Mutex::new(state));
+        let syncer = items
+            .for_each(clone!((state) move |diff| {
+                state.lock().unwrap().apply_diff(diff);
+                async {}
+            }))
+            .apply(spawn);
+        column
+            .update_raw_el(|raw_el| raw_el.insert(VirtualList(state)).hold_tasks([syncer]))
+            .item(
+                El::<Node>::new()
+                    .width(Val::Percent(100.))
+                    .height_signal(top_spacer.signal().dedupe().map(Val::Px))
+                    .update_raw_el(|raw_el| {
+                        raw_el
+                            // targets are checked against the list's children, which only include the spawned items
+                            .observe(
+                                |event: Trigger<CheckInspectionTargets>,
+                                 parents: Query<&Parent>,
+                                 progresses: Query<&InspectionTargetProgress>,
+                                 inspection_targets: Query<&InspectionTarget>,
+                                 lists: Query<&VirtualList>| {
+                                    let spacer = event.entity();
+                                    if let Some(part) = pending_progress_part(
+                                        spacer,
+                                        &parents,
+                                        &progresses,
+                                        &inspection_targets,
+                                    ) {
+                                        if let Ok(VirtualList(list)) = parents
+                                            .get(spacer)
+                                            .and_then(|parent| lists.get(parent.get()))
+                                        {
+                                            list.reveal(part);
+                                        }
+                                    }
+                                },
+                            )
+                            .on_spawn_with_system(|In(entity), mut commands: Commands| {
+                                commands.trigger_targets(CheckInspectionTargets, entity)
+                            })
+                    }),
+            )
+            .items_signal_vec(visible.signal_vec_cloned().map(move |(item, height)| {
+                f(item).update_raw_el(clone!((heights_dirty) move |raw_el| {
+                    raw_el.insert(VirtualItemHeight { height, dirty: heights_dirty })
+                }))
+            }))
+            .item(
+                El::<Node>::new()
+                    .width(Val::Percent(100.))
+                    .height_signal(bottom_spacer.signal().dedupe().map(Val::Px)),
+            )
+    }
+}
+
+fn sync_virtual_item_heights(
+    items: Query<(&VirtualItemHeight, &ComputedNode), Changed<ComputedNode>>,
+) {
+    for (VirtualItemHeight { height, dirty }, computed_node) in items.iter() {
+        let size = computed_node.size();
+        if size.y > 0. && height.get() != size.y {
+            height.set(size.y);
+            dirty.store(true, AtomicOrdering::Relaxed);
+        }
+    }
+}
+
+fn sync_virtual_lists(
+    lists: Query<(Entity, &VirtualList)>,
+    relative_rect: RelativeRect,
+    inspector_column_ancestor: InspectorColumnAncestor,
+    mutable_viewports: Query<&MutableViewport>,
+) {
+    for (entity, VirtualList(list)) in lists.iter() {
+        if let Some((rect, inspector_column)) = relative_rect
+            .get(entity)
+            .zip(inspector_column_ancestor.get(entity))
+        {
+            if let Ok(MutableViewport { viewport, .. }) = mutable_viewports.get(inspector_column) {
+                list.sync(
+                    -rect.min.y - VIRTUALIZATION_OVERSCAN,
+                    -rect.min.y + viewport.height + VIRTUALIZATION_OVERSCAN,
+                );
+            }
+        }
+    }
+}
+
 #[derive(Component, Default)]
 struct SyncUiOnce;
 
+// the value a field or component header was last synced with, along with its component's change tick
+#[derive(Component)]
+struct SeenValue {
+    tick: Option<Tick>,
+    value: Box<dyn PartialReflect>,
+}
+
+// component headers highlight changes to their component even when collapsed
+#[derive(Component)]
+struct ComponentChangeListener;
+
+// how long a component header shows its "changed" badge after a change
+const RECENTLY_CHANGED_DURATION: f32 = 2.;
+
+#[derive(Component)]
+struct ChangeHighlight {
+    // 1 on change, decays to 0 over `GLOBAL_CHANGE_HIGHLIGHT_DECAY` seconds
+    flash: Mutable<f32>,
+    recently_changed: Mutable<bool>,
+    flashed_at: Option<f32>,
+    changed_at: Option<f32>,
+}
+
+impl ChangeHighlight {
+    fn new(flash: Mutable<f32>, recently_changed: Mutable<bool>) -> Self {
+        Self {
+            flash,
+            recently_changed,
+            flashed_at: None,
+            changed_at: None,
+        }
+    }
+}
+
+// the tick at which the inspector last mutably accessed each component, which marks it changed
+#[derive(Resource, Default)]
+struct InspectorEdits(HashMap<(ComponentOwnerType, ComponentId), Tick>);
+
+// edits of despawned entities and removed components or resources can't be shown anymore
+fn prune_inspector_edits(world: &mut World) {
+    world.resource_scope(|world, mut edits: Mut<InspectorEdits>| {
+        edits.0.retain(|&(owner, component), _| match owner {
+            ComponentOwnerType::Entity(entity) => world
+                .get_entity(entity)
+                .is_ok_and(|entity| entity.contains_id(component)),
+            ComponentOwnerType::Resource => {
+                world.get_resource_change_ticks_by_id(component).is_some()
+            }
+        });
+    });
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct ChangeTicksData {
+    // ticks since added/changed
+    added: u32,
+    changed: u32,
+    changed_this_frame: bool,
+    changed_by_inspector: bool,
+}
+
+#[derive(Component)]
+struct ChangeTicksListener {
+    owner: ComponentOwnerType,
+    component: ComponentId,
+    data: Mutable<Option<ChangeTicksData>>,
+}
+
+fn sync_change_ticks(
+    world: &World,
+    listeners: Query<&ChangeTicksListener>,
+    edits: Res<InspectorEdits>,
+    system_change_tick: SystemChangeTick,
+) {
+    let now = world.read_change_tick();
+    for &ChangeTicksListener {
+        owner,
+        component,
+        ref data,
+    } in listeners.iter()
+    {
+        let ticks_option = match owner {
+            ComponentOwnerType::Entity(entity) => world
+                .get_entity(entity)
+                .ok()
+                .and_then(|entity| entity.get_change_ticks_by_id(component)),
+            ComponentOwnerType::Resource => world.get_resource_change_ticks_by_id(component),
+        };
+        data.set_neq(ticks_option.map(|ticks| ChangeTicksData {
+            added: now.get().wrapping_sub(ticks.added.get()),
+            changed: now.get().wrapping_sub(ticks.changed.get()),
+            changed_this_frame:
+                ticks.is_changed(system_change_tick.last_run(), system_change_tick.this_run()),
+            changed_by_inspector: edits.0.get(&(owner, component)) == Some(&ticks.changed),
+        }));
+    }
+}
+
+// a component's added and last changed ticks relative to the current tick
+fn change_ticks(owner: ComponentOwnerType, component: ComponentId) -> impl Element {
+    let column_gap = GLOBAL_COLUMN_GAP.clone();
+    let type_path_color = GLOBAL_TERTIARY_BACKGROUND_COLOR.clone();
+    let highlighted_color = GLOBAL_HIGHLIGHTED_COLOR.clone();
+    let error_color = GLOBAL_ERROR_COLOR.clone();
+    let data = Mutable::new(None);
+    let flag = clone!((data) move |f: fn(&ChangeTicksData) -> bool| {
+        data.signal_ref(move |data| data.as_ref().map(f).unwrap_or(false)).dedupe()
+    });
+    Row::<Node>::new()
+        .apply(row_style(column_gap.signal()))
+        .update_raw_el(clone!((data) move |raw_el| raw_el.insert(ChangeTicksListener { owner, component, data })))
+        .item(
+            DynamicText::new()
+            .text_signal(data.signal_ref(|data| {
+                data.map(|ChangeTicksData { added, changed, .. }| format!("added -{added} changed -{changed}"))
+                .unwrap_or_default()
+            }))
+            .color_signal(type_path_color.signal())
+            .apply(text_no_wrap)
+        )
+        .item_signal(flag(|data| data.changed_this_frame).map_true(move || {
+            DynamicText::new()
+            .text("changed this frame".to_string())
+            .color_signal(highlighted_color.signal())
+            .apply(text_no_wrap)
+        }))
+        .item_signal(flag(|data| data.changed_by_inspector).map_true(move || {
+            DynamicText::new()
+            .text("changed by inspector edit".to_string())
+            .color_signal(error_color.signal())
+            .apply(text_no_wrap)
+        }))
+}
+
+// whether the value at `field_path` of `target` differs from the one `ui_entity` last saw; components and resources
+// are only compared once their change tick moves, while assets, whose `Assets<A>` is mutably borrowed every frame
+// and whose `AssetEvent`s can't be read without their type, are always compared
+fn changed_since_seen(
+    world: &mut World,
+    target: AccessoryTarget,
+    field_path: &ParsedPath,
+    ui_entity: Entity,
+) -> bool {
+    let tick_option = match target {
+        AccessoryTarget::Component { owner, component } => {
+            let ticks_option = match owner {
+                ComponentOwnerType::Entity(entity) => world
+                    .get_entity(entity)
+                    .ok()
+                    .and_then(|entity| entity.get_change_ticks_by_id(component)),
+                ComponentOwnerType::Resource => world.get_resource_change_ticks_by_id(component),
+            };
+            let Some(ticks) = ticks_option else {
+                return false;
+            };
+            Some(ticks.changed)
+        }
+        AccessoryTarget::Asset { .. } => None,
+        AccessoryTarget::EventDraft { .. } => return false,
+    };
+    if tick_option.is_some()
+        && world
+            .get::<SeenValue>(ui_entity)
+            .is_some_and(|seen| seen.tick == tick_option)
+    {
+        return false;
+    }
+    let Some(cur) = reflect_accessory_target(world, target).and_then(|reflect| {
+        reflect
+            .reflect_path(field_path)
+            .ok()
+            .map(PartialReflect::clone_value)
+    }) else {
+        return false;
+    };
+    let Ok(mut entity) = world.get_entity_mut(ui_entity) else {
+        return false;
+    };
+    let Some(mut seen) = entity.get_mut::<SeenValue>() else {
+        entity.insert(SeenValue {
+            tick: tick_option,
+            value: cur,
+        });
+        return false;
+    };
+    seen.tick = tick_option;
+    // values that can't be compared fall back to their tick
+    let changed = seen
+        .value
+        .reflect_partial_eq(cur.as_ref())
+        .map_or(tick_option.is_some(), |eq| !eq);
+    if changed {
+        seen.value = cur;
+    }
+    changed
+}
+
+fn sync_component_changes(world: &mut World) {
+    let mut listeners =
+        world.query_filtered::<(Entity, &Accessory), With<ComponentChangeListener>>();
+    let listeners = listeners
+        .iter(world)
+        .map(|(ui_entity, &Accessory { target, .. })| (ui_entity, target))
+        .collect::<Vec<_>>();
+    for (ui_entity, target) in listeners {
+        if changed_since_seen(world, target, &ParsedPath(vec![]), ui_entity) {
+            highlight_change(world, ui_entity);
+        }
+    }
+}
+
+// flashes the closest field and marks all its ancestor fields as recently changed
+fn highlight_change(world: &mut World, ui_entity: Entity) {
+    let now = world.resource::<Time>().elapsed_secs();
+    let mut flashed = false;
+    let mut cur = Some(ui_entity);
+    while let Some(entity) = cur {
+        if let Some(mut highlight) = world.get_mut::<ChangeHighlight>(entity) {
+            if !flashed {
+                highlight.flashed_at = Some(now);
+                flashed = true;
+            }
+            highlight.changed_at = Some(now);
+        }
+        cur = world.get::<Parent>(entity).map(Parent::get);
+    }
+}
+
+fn decay_change_highlights(time: Res<Time>, mut highlights: Query<&mut ChangeHighlight>) {
+    let now = time.elapsed_secs();
+    let decay = GLOBAL_CHANGE_HIGHLIGHT_DECAY.get().max(f32::EPSILON);
+    for mut highlight in highlights.iter_mut() {
+        let ChangeHighlight {
+            flash,
+            recently_changed,
+            flashed_at,
+            changed_at,
+        } = &mut *highlight;
+        if let Some(at) = *flashed_at {
+            let remaining = (1. - (now - at) / decay).max(0.);
+            flash.set_neq(remaining);
+            if remaining <= 0. {
+                *flashed_at = None;
+            }
+        }
+        if let Some(at) = *changed_at {
+            let recent = now - at < RECENTLY_CHANGED_DURATION;
+            recently_changed.set_neq(recent);
+            if !recent {
+                *changed_at = None;
+            }
+        }
+    }
+}
+
 #[derive(Component, Default)]
 struct SyncComponentsOnce;
 
+// for listeners that sync regardless of visibility, e.g. plots that keep recording while scrolled out of view
+#[derive(Component, Default)]
+struct SyncUiAlways;
+
 #[allow(clippy::type_complexity)]
 fn sync_ui(
     field_listeners: Query<
         (Entity, &Accessory, &FieldListener),
-        Or<(With<Visible>, With<SyncUiOnce>)>,
+        Or<(With<Visible>, With<SyncUiOnce>, With<SyncUiAlways>)>,
     >,
     mut field_path_cached: FieldPathCached,
     mut commands: Commands,
@@ -5099,16 +9564,7 @@ fn sync_ui(
     {
         let field_path = field_path_cached.get(ui_entity);
         commands.queue(move |world: &mut World| {
-            if let Some(cur) = match target {
-                AccessoryTarget::Component { owner, component } => match owner {
-                    ComponentOwnerType::Entity(entity) => {
-                        reflect_component(world, entity, component)
-                    }
-                    ComponentOwnerType::Resource => reflect_resource(world, component),
-                },
-                AccessoryTarget::Asset { asset, handle } => reflect_asset(world, asset, handle),
-            }
-            .and_then(|reflect| {
+            if let Some(cur) = reflect_accessory_target(world, target).and_then(|reflect| {
                 reflect
                     .reflect_path(&field_path)
                     .ok()
@@ -5118,12 +9574,15 @@ fn sync_ui(
                 if let Ok(mut entity) = world.get_entity_mut(ui_entity) {
                     entity.remove::<SyncUiOnce>();
                 }
+                if changed_since_seen(world, target, &field_path, ui_entity) {
+                    highlight_change(world, ui_entity);
+                }
             }
         });
     }
 }
 
-#[derive(Clone, Debug, Component)]
+#[derive(Clone, PartialEq, Debug, Component)]
 struct InspectionTargetMutliField {
     name: String,
     field: Option<InspectionTargetField>,
@@ -5166,18 +9625,49 @@ impl TryFrom<&str> for InspectionTargetRoot {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 enum InspectionTargetInner {
     Multi(InspectionTargetMutliField),
     Solo(InspectionTargetField),
 }
 
-#[derive(Clone, Debug, Component)]
+#[derive(Clone, PartialEq, Debug, Component)]
 pub struct InspectionTarget {
     root: InspectionTargetRoot,
     target: Option<InspectionTargetInner>,
 }
 
+// formatted like the tuples accepted by `Inspector::jump_to`
+impl Display for InspectionTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![self.root.to_string().to_lowercase()];
+        let field_parts = |InspectionTargetField { field, path }: &InspectionTargetField| {
+            let mut parts = vec![field.clone()];
+            if let Some(path) = path {
+                parts.push(path.to_string());
+            }
+            parts
+        };
+        match &self.target {
+            Some(InspectionTargetInner::Multi(InspectionTargetMutliField { name, field })) => {
+                parts.push(name.clone());
+                parts.extend(field.iter().flat_map(field_parts));
+            }
+            Some(InspectionTargetInner::Solo(field)) => parts.extend(field_parts(field)),
+            None => (),
+        }
+        if let [root] = parts.as_slice() {
+            write!(f, "{root:?}")
+        } else {
+            let parts = parts
+                .iter()
+                .map(|part| format!("{part:?}"))
+                .collect::<Vec<_>>();
+            write!(f, "({})", parts.join(", "))
+        }
+    }
+}
+
 impl From<(InspectionTargetRoot, &str, &str, &str)> for InspectionTarget {
     fn from((root, multi_field, field, path): (InspectionTargetRoot, &str, &str, &str)) -> Self {
         if matches!(root, InspectionTargetRoot::Resource) {
@@ -5298,8 +9788,16 @@ struct RemoveTarget {
     from: Entity,
 }
 
-fn unfocus_text_input_on_keys(input: Res<ButtonInput<KeyCode>>, mut commands: Commands) {
-    if input.any_just_pressed([KeyCode::Escape, KeyCode::Enter]) {
+fn unfocus_text_input_on_keys(
+    input: Res<ButtonInput<KeyCode>>,
+    focused_text_input_option: Option<Res<FocusedTextInput>>,
+    multiline_text_inputs: Query<(), With<MultilineTextInput>>,
+    mut commands: Commands,
+) {
+    // multiline inputs handle enter themselves
+    let multiline =
+        focused_text_input_option.is_some_and(|focused| multiline_text_inputs.contains(**focused));
+    if input.just_pressed(KeyCode::Escape) || (input.just_pressed(KeyCode::Enter) && !multiline) {
         commands.remove_resource::<FocusedTextInput>();
     }
 }
@@ -5711,6 +10209,413 @@ enum Tab {
     Down,
 }
 
+#[derive(Component, Default)]
+struct Hovered;
+
+// what the clipboard and scene hotkeys act on when hovered
+#[derive(Component, Clone)]
+enum HotkeyTarget {
+    Entity(Entity),
+    Field {
+        target: AccessoryTarget,
+        path: ParsedPath,
+    },
+    Resources(MutableBTreeMap<ComponentId, FieldData>),
+}
+
+fn reflect_accessory_target(world: &mut World, target: AccessoryTarget) -> Option<&dyn Reflect> {
+    match target {
+        AccessoryTarget::Component { owner, component } => match owner {
+            ComponentOwnerType::Entity(entity) => reflect_component(world, entity, component),
+            ComponentOwnerType::Resource => reflect_resource(world, component),
+        },
+        AccessoryTarget::Asset { asset, handle } => reflect_asset(world, asset, handle),
+        AccessoryTarget::EventDraft { events } => reflect_event_draft(world, events),
+    }
+}
+
+fn field_inspection_target(
+    world: &World,
+    target: AccessoryTarget,
+    path: &ParsedPath,
+) -> Option<InspectionTarget> {
+    let path = path.to_string();
+    Some(match target {
+        AccessoryTarget::Component { owner, component } => {
+            let name = ShortName(world.components().get_info(component)?.name()).to_string();
+            match owner {
+                ComponentOwnerType::Entity(entity) => InspectionTarget::from((
+                    InspectionTargetRoot::Entity,
+                    entity.to_string().as_str(),
+                    name.as_str(),
+                    path.as_str(),
+                )),
+                ComponentOwnerType::Resource => InspectionTarget::from((
+                    InspectionTargetRoot::Resource,
+                    name.as_str(),
+                    path.as_str(),
+                )),
+            }
+        }
+        AccessoryTarget::Asset { asset, handle } => {
+            let name = ShortName(
+                world
+                    .resource::<AppTypeRegistry>()
+                    .read()
+                    .get(asset)?
+                    .type_info()
+                    .type_path(),
+            )
+            .to_string();
+            let handle = handle_name(handle, world.get_resource::<AssetServer>()?);
+            InspectionTarget::from((
+                InspectionTargetRoot::Asset,
+                name.as_str(),
+                handle.as_str(),
+                path.as_str(),
+            ))
+        }
+        // drafts live outside the world, so there's nothing to jump to
+        AccessoryTarget::EventDraft { .. } => return None,
+    })
+}
+
+fn field_ron(world: &mut World, target: AccessoryTarget, path: &ParsedPath) -> Option<String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let field = reflect_accessory_target(world, target)?
+        .reflect_path(path)
+        .ok()?;
+    let type_registry = type_registry.read();
+    ron::ser::to_string_pretty(
+        &TypedReflectSerializer::new(field, &type_registry),
+        ron::ser::PrettyConfig::default(),
+    )
+    .inspect_err(|e| warn!("failed to serialize {path} as RON: {e}"))
+    .ok()
+}
+
+fn paste_ron(
+    world: &mut World,
+    target: AccessoryTarget,
+    path: &ParsedPath,
+    ron: &str,
+    settings: EditSettings,
+) {
+    if let Err(e) = apply_ron(world, target, path, ron, settings) {
+        warn!("failed to paste into {path}: {e}");
+    }
+}
+
+fn apply_ron(
+    world: &mut World,
+    target: AccessoryTarget,
+    path: &ParsedPath,
+    ron: &str,
+    settings: EditSettings,
+) -> Result<(), String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_id = reflect_accessory_target(world, target)
+        .and_then(|reflect| reflect.reflect_path(path).ok())
+        .and_then(PartialReflect::get_represented_type_info)
+        .map(TypeInfo::type_id)
+        .ok_or_else(|| format!("{path} does not exist"))?;
+    let value = {
+        let type_registry = type_registry.read();
+        let registration = type_registry
+            .get(type_id)
+            .ok_or_else(|| "type is not registered in the `TypeRegistry`".to_string())?;
+        let mut deserializer = ron::Deserializer::from_str(ron).map_err(|e| e.to_string())?;
+        TypedReflectDeserializer::new(registration, &type_registry)
+            .deserialize(&mut deserializer)
+            .map_err(|e| e.to_string())?
+    };
+    edit_field(world, target, path, value.as_ref(), settings)
+}
+
+fn copy_component(world: &mut World, entity: Entity, component: ComponentId) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let Some(reflect) = reflect_component(world, entity, component) else {
+        return;
+    };
+    let result = ron::ser::to_string_pretty(
+        &ReflectSerializer::new(reflect.as_partial_reflect(), &type_registry.read()),
+        ron::ser::PrettyConfig::default(),
+    );
+    match result {
+        Ok(ron) => world.resource_mut::<Clipboard>().set(ron),
+        Err(e) => warn!("failed to serialize component as RON: {e}"),
+    }
+}
+
+// the clipboard's RON as a registered component, rejecting anything else before it's written
+fn clipboard_component(
+    world: &mut World,
+) -> Result<(Box<dyn PartialReflect>, TypeId, ReflectComponent), String> {
+    let ron = world
+        .resource_mut::<Clipboard>()
+        .get()
+        .ok_or("clipboard is empty")?;
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let mut deserializer = ron::Deserializer::from_str(&ron).map_err(|e| e.to_string())?;
+    let value = ReflectDeserializer::new(&type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|e| e.to_string())?;
+    let type_id = value
+        .get_represented_type_info()
+        .map(TypeInfo::type_id)
+        .ok_or_else(|| format!("{} is not a registered type", value.reflect_type_path()))?;
+    let reflect_component = type_registry
+        .get_type_data::<ReflectComponent>(type_id)
+        .cloned()
+        .ok_or_else(|| {
+            format!(
+                "{} is not a registered component",
+                value.reflect_type_path()
+            )
+        })?;
+    Ok((value, type_id, reflect_component))
+}
+
+fn paste_component(
+    world: &mut World,
+    entity: Entity,
+    settings: EditSettings,
+) -> Result<(), String> {
+    let (value, type_id, reflect_component) = clipboard_component(world)?;
+    let existing = world.components().get_id(type_id).filter(|&component| {
+        world
+            .get_entity(entity)
+            .is_ok_and(|entity| entity.contains_id(component))
+    });
+    if let Some(component) = existing {
+        let target = AccessoryTarget::Component {
+            owner: ComponentOwnerType::Entity(entity),
+            component,
+        };
+        edit_field(world, target, &ParsedPath(vec![]), value.as_ref(), settings)
+    } else {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let mut entity = world.get_entity_mut(entity).map_err(|e| e.to_string())?;
+        reflect_component.insert(&mut entity, value.as_ref(), &type_registry.read());
+        Ok(())
+    }
+}
+
+// the innermost hovered hotkey target
+fn hovered_hotkey_target(world: &mut World) -> Option<(Entity, HotkeyTarget)> {
+    let mut hovered = world.query_filtered::<(Entity, &HotkeyTarget), With<Hovered>>();
+    let world = &*world;
+    let depth = |mut entity: Entity| {
+        let mut depth = 0;
+        while let Some(parent) = world.get::<Parent>(entity) {
+            entity = parent.get();
+            depth += 1;
+        }
+        depth
+    };
+    hovered
+        .iter(world)
+        .max_by_key(|&(entity, _)| depth(entity))
+        .map(|(entity, source)| (entity, source.clone()))
+}
+
+fn clipboard_hotkeys(world: &mut World) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    if !keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) {
+        return;
+    }
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    let (copy, paste) = (
+        keys.just_pressed(KeyCode::KeyC),
+        keys.just_pressed(KeyCode::KeyV),
+    );
+    if !copy && !paste {
+        return;
+    }
+    let Some((ui_entity, source)) = hovered_hotkey_target(world) else {
+        return;
+    };
+    let text = match source {
+        HotkeyTarget::Resources(_) => return,
+        HotkeyTarget::Entity(entity) => {
+            if !copy {
+                return;
+            }
+            if alt {
+                InspectionTarget::from((InspectionTargetRoot::Entity, entity.to_string().as_str()))
+                    .to_string()
+            } else if shift {
+                let Some(name) = world.get::<Name>(entity) else {
+                    return;
+                };
+                name.to_string()
+            } else {
+                entity.to_string()
+            }
+        }
+        HotkeyTarget::Field { target, path } => {
+            if paste {
+                let mut system_state = SystemState::<ClosestFieldAttributes>::new(world);
+                if system_state.get(world).read_only(ui_entity) {
+                    return;
+                }
+                let Some(ron) = world.resource_mut::<Clipboard>().get() else {
+                    return;
+                };
+                let settings = EditSettings::closest_in_world(world, ui_entity);
+                paste_ron(world, target, &path, &ron, settings);
+                return;
+            }
+            if alt {
+                let Some(inspection_target) = field_inspection_target(world, target, &path) else {
+                    return;
+                };
+                inspection_target.to_string()
+            } else if shift {
+                match target {
+                    // asset roots copy their handle's name
+                    AccessoryTarget::Asset { handle, .. } if path.0.is_empty() => {
+                        let Some(asset_server) = world.get_resource::<AssetServer>() else {
+                            return;
+                        };
+                        handle_name(handle, asset_server)
+                    }
+                    _ => path.to_string(),
+                }
+            } else {
+                let Some(ron) = field_ron(world, target, &path) else {
+                    return;
+                };
+                ron
+            }
+        }
+    };
+    world.resource_mut::<Clipboard>().set(text);
+}
+
+fn resource_scene_name(world: &World, component: ComponentId) -> Option<(TypeId, String)> {
+    let info = world.components().get_info(component)?;
+    Some((info.type_id()?, ShortName(info.name()).to_string()))
+}
+
+// entities are exported with their descendants when `with_descendants`
+fn export_hotkey_target(
+    world: &mut World,
+    target: HotkeyTarget,
+    with_descendants: bool,
+) -> Result<(), String> {
+    let scene_files = world.get_resource_or_init::<SceneFiles>().clone();
+    match target {
+        HotkeyTarget::Entity(entity)
+        | HotkeyTarget::Field {
+            target:
+                AccessoryTarget::Component {
+                    owner: ComponentOwnerType::Entity(entity),
+                    ..
+                },
+            ..
+        } => {
+            let path = scene_files.path(&entity_scene_name(world, entity));
+            export_entity(world, entity, with_descendants, &path)
+        }
+        HotkeyTarget::Field {
+            target:
+                AccessoryTarget::Component {
+                    owner: ComponentOwnerType::Resource,
+                    component,
+                },
+            ..
+        } => {
+            let (type_id, name) =
+                resource_scene_name(world, component).ok_or("resource is not reflectable")?;
+            export_resources(world, [type_id], &scene_files.path(&name))
+        }
+        HotkeyTarget::Resources(resources) => {
+            // only the resources that pass the current search
+            let type_ids = resources
+                .lock_ref()
+                .iter()
+                .filter(|(_, FieldData { filtered, .. })| !filtered.get())
+                .filter_map(|(&component, _)| world.components().get_info(component)?.type_id())
+                .collect::<Vec<_>>();
+            export_resources(world, type_ids, &scene_files.path("resources"))
+        }
+        HotkeyTarget::Field { .. } => {
+            Err("only entities and resources export to scenes".to_string())
+        }
+    }
+}
+
+// imports the scene file `target` exports to, otherwise the last exported scene
+fn import_hotkey_target(world: &mut World, target: Option<HotkeyTarget>) -> Result<(), String> {
+    let scene_files = world.get_resource_or_init::<SceneFiles>().clone();
+    let path = match target {
+        Some(
+            HotkeyTarget::Entity(entity)
+            | HotkeyTarget::Field {
+                target:
+                    AccessoryTarget::Component {
+                        owner: ComponentOwnerType::Entity(entity),
+                        ..
+                    },
+                ..
+            },
+        ) => Some(scene_files.path(&entity_scene_name(world, entity))),
+        Some(HotkeyTarget::Field {
+            target:
+                AccessoryTarget::Component {
+                    owner: ComponentOwnerType::Resource,
+                    component,
+                },
+            ..
+        }) => resource_scene_name(world, component).map(|(_, name)| scene_files.path(&name)),
+        Some(HotkeyTarget::Resources(_)) => Some(scene_files.path("resources")),
+        _ => None,
+    };
+    let path = path
+        .or_else(|| scene_files.last_exported().map(Path::to_path_buf))
+        .ok_or_else(|| format!("no scene to import, export a .{SCENE_EXTENSION} first"))?;
+    import_scene(world, &path)
+}
+
+fn scene_hotkeys(world: &mut World) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    if !keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) {
+        return;
+    }
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let (export, import) = (
+        keys.just_pressed(KeyCode::KeyS),
+        keys.just_pressed(KeyCode::KeyO),
+    );
+    let result = if export {
+        let Some((_, target)) = hovered_hotkey_target(world) else {
+            return;
+        };
+        export_hotkey_target(world, target, shift)
+    } else if import {
+        let target = hovered_hotkey_target(world).map(|(_, target)| target);
+        import_hotkey_target(world, target)
+    } else {
+        return;
+    };
+    if let Err(e) = result {
+        warn!("{e}");
+    }
+}
+
 // TODO: make hotkeys configurable
 fn hotkey_forwarder(
     keys: Res<ButtonInput<KeyCode>>,
@@ -6372,75 +11277,480 @@ pub static AALO_TEXT_CAMERA_RENDER_LAYERS: Lazy<RenderLayers> = Lazy::new(|| {
 )]
 struct AaloTextCamera;
 
-pub static RESOURCES: Lazy<MutableBTreeMap<ComponentId, FieldData>> = Lazy::new(default);
+pub static RESOURCES: Lazy<MutableBTreeMap<ComponentId, FieldData>> = Lazy::new(default);
+
+#[derive(Component)]
+pub struct SyncResources;
+
+fn sync_resources(type_registry: Res<AppTypeRegistry>, components: &Components) {
+    let mut new = HashSet::new();
+    let old = RESOURCES.lock_ref().keys().copied().collect::<HashSet<_>>();
+    let type_registry = type_registry.read();
+    for registration in type_registry.iter() {
+        if registration.data::<ReflectResource>().is_some() {
+            if let Some(component) = components.get_resource_id(registration.type_id()) {
+                new.insert(component);
+            }
+        }
+    }
+    let mut resources = RESOURCES.lock_mut();
+    for component in new.difference(&old).copied() {
+        if let Some(info) = components.get_info(component) {
+            resources.insert_cloned(
+                component,
+                FieldData {
+                    name: info.name().to_string(),
+                    ..default()
+                },
+            );
+        }
+    }
+    for component in old.difference(&new) {
+        resources.remove(component);
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct AssetData {
+    pub name: &'static str,
+    pub expanded: Mutable<bool>,
+    pub filtered: Mutable<bool>,
+    pub handles: MutableBTreeMap<UntypedAssetId, FieldData>,
+}
+
+pub static ASSETS: Lazy<MutableBTreeMap<TypeId, AssetData>> = Lazy::new(default);
+
+#[derive(Component)]
+pub struct SyncAssets;
+
+fn sync_assets(type_registry: Res<AppTypeRegistry>) {
+    let mut new = HashSet::new();
+    let old = ASSETS.lock_ref().keys().copied().collect::<HashSet<_>>();
+    let type_registry = type_registry.read();
+    for registration in type_registry.iter() {
+        if registration.data::<ReflectAsset>().is_some() {
+            new.insert(registration.type_id());
+        }
+    }
+    let mut assets = ASSETS.lock_mut();
+    for asset in new.difference(&old).copied() {
+        if let Some(info) = type_registry.get_type_info(asset) {
+            assets.insert_cloned(
+                asset,
+                AssetData {
+                    name: info.type_path(),
+                    ..default()
+                },
+            );
+        }
+    }
+    for asset in old.difference(&new) {
+        assets.remove(asset);
+    }
+}
+
+/// Every `Schedule` in `Schedules`, keyed by its label.
+pub static SCHEDULES: Lazy<MutableBTreeMap<String, ScheduleData>> = Lazy::new(default);
+
+static STEPPING_ENABLED: Lazy<Mutable<bool>> = Lazy::new(default);
+
+#[derive(Clone)]
+pub struct ScheduleData {
+    pub label: InternedScheduleLabel,
+    /// Whether the schedule has been added to `Stepping`.
+    pub stepping: Mutable<bool>,
+    /// Systems in execution order, or insertion order if the schedule has not been initialized.
+    pub systems: MutableVec<SystemData>,
+    // whether `systems` was last collected from the initialized schedule, which has the ambiguities
+    initialized: Mutable<bool>,
+}
+
+#[derive(Clone)]
+pub struct SystemData {
+    pub node: NodeId,
+    pub name: String,
+    pub sets: Vec<String>,
+    pub conditions: Vec<String>,
+    pub ambiguities: Vec<String>,
+    /// Ticks since the system last ran.
+    pub last_run: Mutable<Option<u32>>,
+    /// Whether `Stepping` will run this system next.
+    pub cursor: Mutable<bool>,
+    pub behavior: Mutable<Option<SteppingBehavior>>,
+    pub expanded: Mutable<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Display, EnumIter, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum SteppingBehavior {
+    Always,
+    Never,
+    Break,
+}
+
+#[derive(Component)]
+pub struct SyncSchedules;
+
+fn schedule_systems(world: &World, schedule: &Schedule, old: &[SystemData]) -> Vec<SystemData> {
+    let graph = schedule.graph();
+    let mut names = HashMap::new();
+    let mut nodes = vec![];
+    if let Ok(systems) = schedule.systems() {
+        for (node, system) in systems {
+            names.insert(node, system.name().to_string());
+            nodes.push(node);
+        }
+    }
+    // bevy moves run conditions into the executable schedule on initialization, so they're
+    // only available for schedules that are seen before they first run
+    let mut conditions = HashMap::<NodeId, Vec<String>>::new();
+    if nodes.is_empty() {
+        for (node, system, system_conditions) in graph.systems() {
+            names.insert(node, system.name().to_string());
+            nodes.push(node);
+            conditions.insert(
+                node,
+                system_conditions
+                    .iter()
+                    .map(|condition| condition.name().to_string())
+                    .collect(),
+            );
+        }
+    }
+    let set_conditions = graph
+        .system_sets()
+        .filter(|(_, _, set_conditions)| !set_conditions.is_empty())
+        .map(|(node, set, set_conditions)| {
+            (
+                node,
+                set_conditions
+                    .iter()
+                    .map(|condition| format!("{set:?}: {}", condition.name()))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let mut sets = HashMap::<NodeId, Vec<NodeId>>::new();
+    for (parent, child, _) in graph.hierarchy().graph().all_edges() {
+        sets.entry(child).or_default().push(parent);
+    }
+    let mut ambiguities = HashMap::<NodeId, Vec<String>>::new();
+    for (left, right, conflicts) in graph.conflicting_systems() {
+        let on = if conflicts.is_empty() {
+            "world".to_string()
+        } else {
+            conflicts
+                .as_slice()
+                .iter()
+                .filter_map(|&component| world.components().get_name(component))
+                .map(|name| ShortName(name).to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        for (node, other) in [(left, right), (right, left)] {
+            if let Some(name) = names.get(other) {
+                ambiguities
+                    .entry(*node)
+                    .or_default()
+                    .push(format!("{} on {on}", ShortName(name)));
+            }
+        }
+    }
+    let old = old
+        .iter()
+        .map(|data| (data.node, data))
+        .collect::<HashMap<_, _>>();
+    nodes
+        .into_iter()
+        .map(|node| {
+            let parents = sets.remove(&node).unwrap_or_default();
+            let mut system_conditions = conditions.remove(&node).unwrap_or_default();
+            for parent in parents.as_slice().iter() {
+                if let Some(set_conditions) = set_conditions.get(parent) {
+                    system_conditions.extend(set_conditions.as_slice().iter().cloned());
+                }
+            }
+            let old = old.get(&node);
+            if system_conditions.is_empty() {
+                if let Some(old) = old {
+                    system_conditions = old.conditions.clone();
+                }
+            }
+            SystemData {
+                node,
+                name: names.remove(&node).unwrap_or_default(),
+                sets: parents
+                    .into_iter()
+                    .filter_map(|parent| graph.get_set_at(parent))
+                    // skip the sets bevy creates for each system type
+                    .filter(|set| set.system_type().is_none() && !set.is_anonymous())
+                    .map(|set| format!("{set:?}"))
+                    .collect(),
+                conditions: system_conditions,
+                ambiguities: ambiguities.remove(&node).unwrap_or_default(),
+                last_run: Mutable::new(None),
+                cursor: Mutable::new(false),
+                behavior: old.map(|old| old.behavior.clone()).unwrap_or_default(),
+                expanded: old.map(|old| old.expanded.clone()).unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+// a schedule is removed from `Schedules` while it runs, so this runs in `Main` after `Main::run_main`,
+// where only `Main` is missing, and once from the plugin's cleanup, before any schedule has run and taken its run conditions
+pub(crate) fn sync_schedules(world: &World) {
+    let Some(schedules) = world.get_resource::<Schedules>() else {
+        return;
+    };
+    let stepping = world.get_resource::<Stepping>();
+    STEPPING_ENABLED.set_neq(stepping.is_some_and(Stepping::is_enabled));
+    let cursor = stepping.and_then(Stepping::cursor);
+    let stepping_schedules = stepping.and_then(|stepping| stepping.schedules().ok());
+    let now = world.read_change_tick();
+    for (_, schedule) in schedules.iter() {
+        let label = schedule.label();
+        let key = format!("{label:?}");
+        let existing = SCHEDULES.lock_ref().get(&key).cloned();
+        let data = existing.unwrap_or_else(|| {
+            let data = ScheduleData {
+                label,
+                stepping: Mutable::new(false),
+                systems: MutableVec::new(),
+                initialized: Mutable::new(false),
+            };
+            SCHEDULES.lock_mut().insert_cloned(key, data.clone());
+            data
+        });
+        if let Some(stepping_schedules) = stepping_schedules {
+            data.stepping.set_neq(stepping_schedules.contains(&label));
+        }
+        let (nodes, initialized) = match schedule.systems() {
+            Ok(systems) => (systems.map(|(node, _)| node).collect::<Vec<_>>(), true),
+            Err(_) => (
+                schedule.graph().systems().map(|(node, ..)| node).collect(),
+                false,
+            ),
+        };
+        let changed = {
+            let systems = data.systems.lock_ref();
+            systems.len() != nodes.len()
+                || systems
+                    .iter()
+                    .zip(nodes.as_slice().iter())
+                    .any(|(data, node)| data.node != *node)
+        };
+        if changed || data.initialized.get() != initialized {
+            data.initialized.set_neq(initialized);
+            let new = schedule_systems(world, schedule, &data.systems.lock_ref());
+            data.systems.lock_mut().replace_cloned(new);
+        }
+        let systems = data.systems.lock_ref();
+        if let Ok(executable) = schedule.systems() {
+            for (data, (_, system)) in systems.iter().zip(executable) {
+                data.last_run
+                    .set_neq(Some(now.get().wrapping_sub(system.get_last_run().get())));
+            }
+        }
+        for data in systems.iter() {
+            data.cursor.set_neq(cursor == Some((label, data.node)));
+        }
+    }
+}
+
+/// Registered `Events<T>` resources whose `T` is reflectable.
+pub static EVENTS: Lazy<MutableBTreeMap<ComponentId, EventsData>> = Lazy::new(default);
+
+#[derive(Clone, Default)]
+pub struct EventsData {
+    /// Type path of the event.
+    pub name: String,
+    pub expanded: Mutable<bool>,
+}
 
 #[derive(Component)]
-pub struct SyncResources;
+pub struct SyncEvents;
 
-fn sync_resources(type_registry: Res<AppTypeRegistry>, components: &Components) {
-    let mut new = HashSet::new();
-    let old = RESOURCES.lock_ref().keys().copied().collect::<HashSet<_>>();
+const EVENTS_TYPE_PATH_PREFIX: &str = "bevy_ecs::event::collections::Events<";
+
+fn event_type(type_info: &TypeInfo) -> Option<TypeId> {
+    type_info
+        .generics()
+        .get_named("E")
+        .map(|generic| generic.type_id())
+}
+
+// `Events<T>` only need rescanning for once their resource is initialized, which registers a new component
+fn sync_events(
+    type_registry: Res<AppTypeRegistry>,
+    components: &Components,
+    mut scanned_components: Local<Option<usize>>,
+) {
+    if *scanned_components == Some(components.len()) {
+        return;
+    }
+    *scanned_components = Some(components.len());
+    let mut new = HashMap::new();
+    let old = EVENTS.lock_ref().keys().copied().collect::<HashSet<_>>();
     let type_registry = type_registry.read();
     for registration in type_registry.iter() {
-        if registration.data::<ReflectResource>().is_some() {
+        let type_info = registration.type_info();
+        if registration.data::<ReflectResource>().is_none()
+            || !type_info.type_path().starts_with(EVENTS_TYPE_PATH_PREFIX)
+        {
+            continue;
+        }
+        if let Some(event) = event_type(type_info).and_then(|event| type_registry.get(event)) {
             if let Some(component) = components.get_resource_id(registration.type_id()) {
-                new.insert(component);
+                new.insert(component, event.type_info().type_path());
             }
         }
     }
-    let mut resources = RESOURCES.lock_mut();
-    for component in new.difference(&old).copied() {
-        if let Some(info) = components.get_info(component) {
-            resources.insert_cloned(
+    let mut events = EVENTS.lock_mut();
+    for (&component, &name) in new.iter() {
+        if !old.contains(&component) {
+            events.insert_cloned(
                 component,
-                FieldData {
-                    name: info.name().to_string(),
+                EventsData {
+                    name: name.to_string(),
                     ..default()
                 },
             );
         }
     }
-    for component in old.difference(&new) {
-        resources.remove(component);
+    for component in old.iter() {
+        if !new.contains_key(component) {
+            events.remove(component);
+        }
     }
 }
 
-#[derive(Clone, Default)]
-pub struct AssetData {
-    pub name: &'static str,
-    pub expanded: Mutable<bool>,
-    pub filtered: Mutable<bool>,
-    pub handles: MutableBTreeMap<UntypedAssetId, FieldData>,
+/// Events being composed in the inspector, keyed by their `Events<T>` resource.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct EventDrafts(HashMap<ComponentId, Box<dyn Reflect>>);
+
+fn reflect_event_draft(world: &mut World, events: ComponentId) -> Option<&dyn Reflect> {
+    world
+        .get_resource::<EventDrafts>()?
+        .get(&events)
+        .map(AsRef::as_ref)
 }
 
-pub static ASSETS: Lazy<MutableBTreeMap<TypeId, AssetData>> = Lazy::new(default);
+// replaces the draft with the event type's default, returning whether one could be constructed
+fn reset_event_draft(world: &mut World, events: ComponentId) -> bool {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let draft = world
+        .components()
+        .get_info(events)
+        .and_then(|info| info.type_id())
+        .and_then(|type_id| type_registry.get_type_info(type_id))
+        .and_then(event_type)
+        .and_then(|event| {
+            let value = default_value(event, &type_registry)?;
+            type_registry
+                .get_type_data::<ReflectFromReflect>(event)?
+                .from_reflect(value.as_ref())
+        });
+    let mut drafts = world.resource_mut::<EventDrafts>();
+    match draft {
+        Some(draft) => {
+            drafts.insert(events, draft);
+            true
+        }
+        None => {
+            drafts.remove(&events);
+            false
+        }
+    }
+}
+
+// sends the draft through the event type's `ReflectEvent`
+fn send_event_draft(world: &mut World, events: ComponentId) -> Result<(), String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let event = world
+        .components()
+        .get_info(events)
+        .and_then(|info| info.type_id())
+        .and_then(|type_id| type_registry.get_type_info(type_id))
+        .and_then(event_type)
+        .ok_or("unknown event type")?;
+    let reflect_event = type_registry
+        .get_type_data::<ReflectEvent>(event)
+        .cloned()
+        .ok_or("`ReflectEvent` is not registered for this event, see `register_reflect_event`")?;
+    drop(type_registry);
+    let draft = world
+        .resource::<EventDrafts>()
+        .get(&events)
+        .ok_or("no event to send")?
+        .clone_value();
+    reflect_event.send(world, draft.as_ref())
+}
 
 #[derive(Component)]
-pub struct SyncAssets;
+struct BufferedEventsListener {
+    events: ComponentId,
+    buffered: Mutable<Vec<String>>,
+    sent: Mutable<usize>,
+}
 
-fn sync_assets(type_registry: Res<AppTypeRegistry>) {
-    let mut new = HashSet::new();
-    let old = ASSETS.lock_ref().keys().copied().collect::<HashSet<_>>();
+fn sync_buffered_events(world: &mut World) {
+    let listeners = world
+        .query::<&BufferedEventsListener>()
+        .iter(world)
+        .map(|listener| {
+            (
+                listener.events,
+                listener.buffered.clone(),
+                listener.sent.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = type_registry.read();
-    for registration in type_registry.iter() {
-        if registration.data::<ReflectAsset>().is_some() {
-            new.insert(registration.type_id());
+    for (events, buffered, sent) in listeners {
+        let Some(ReflectRef::Struct(events)) =
+            reflect_resource(world, events).map(PartialReflect::reflect_ref)
+        else {
+            continue;
+        };
+        if let Some(&count) = events
+            .field("event_count")
+            .and_then(|count| count.try_downcast_ref::<usize>())
+        {
+            sent.set_neq(count);
         }
-    }
-    let mut assets = ASSETS.lock_mut();
-    for asset in new.difference(&old).copied() {
-        if let Some(info) = type_registry.get_type_info(asset) {
-            assets.insert_cloned(
-                asset,
-                AssetData {
-                    name: info.type_path(),
-                    ..default()
-                },
-            );
+        let mut new = vec![];
+        for sequence in ["events_a", "events_b"] {
+            let Some(ReflectRef::Struct(sequence)) =
+                events.field(sequence).map(PartialReflect::reflect_ref)
+            else {
+                continue;
+            };
+            let Some(ReflectRef::List(list)) =
+                sequence.field("events").map(PartialReflect::reflect_ref)
+            else {
+                continue;
+            };
+            for instance in list.iter() {
+                if let ReflectRef::Struct(instance) = instance.reflect_ref() {
+                    if let Some(event) = instance.field("event") {
+                        new.push(
+                            ron::ser::to_string(&TypedReflectSerializer::new(
+                                event,
+                                &type_registry,
+                            ))
+                            .unwrap_or_else(|_| format!("{event:?}")),
+                        );
+                    }
+                }
+            }
+        }
+        if *buffered.lock_ref() != new {
+            buffered.set(new);
         }
-    }
-    for asset in old.difference(&new) {
-        assets.remove(asset);
     }
 }
 
@@ -6546,16 +11856,58 @@ pub(super) fn plugin(app: &mut App) {
             PreUpdate,
             propagate_inspector_bloodline.run_if(any_with_component::<InspectorBloodline>),
         )
+        .add_systems(
+            Main,
+            sync_schedules
+                .after(Main::run_main)
+                .run_if(any_with_component::<SyncSchedules>),
+        )
+        .add_systems(
+            First,
+            pause_after_step
+                .after(TimeSystem)
+                .run_if(resource_exists::<VirtualTimeStep>),
+        )
         .add_systems(
             Update,
             (
-                sync_orphan_entities.run_if(any_with_component::<SyncOrphanEntities>),
-                sync_entities.run_if(any_with_component::<SyncEntities>),
-                sync_components.run_if(any_with_component::<EntityRoot>),
-                sync_resources.run_if(any_with_component::<SyncResources>),
-                sync_assets.run_if(any_with_component::<SyncAssets>),
+                (
+                    track_entity_changes.run_if(entity_changes_consumed()),
+                    untrack_entity_changes.run_if(not(entity_changes_consumed()).and(
+                        |entity_changes: Res<EntityChanges>| !entity_changes.observers.is_empty(),
+                    )),
+                    (
+                        sync_orphan_entities.run_if(any_with_component::<SyncOrphanEntities>),
+                        sync_entities.run_if(any_with_component::<SyncEntities>),
+                        sync_observers.run_if(any_with_component::<SyncObservers>),
+                        sync_components.run_if(any_with_component::<EntityRoot>),
+                        sync_entity_query_filters.run_if(any_with_component::<EntityQueryFilters>),
+                        sync_watched_targets.run_if(any_with_component::<WatchedTarget>),
+                    ),
+                )
+                    .chain(),
+                (
+                    sync_ecs_stats.run_if(any_with_component::<EcsStatsListener>),
+                    filter_entities_by_search.run_if(any_with_component::<EntitySearch>),
+                    search_values.run_if(any_with_component::<ValueSearch>),
+                ),
+                (
+                    sync_resources.run_if(any_with_component::<SyncResources>),
+                    sync_assets.run_if(any_with_component::<SyncAssets>),
+                    sync_events.run_if(any_with_component::<SyncEvents>),
+                    sync_buffered_events.run_if(any_with_component::<BufferedEventsListener>),
+                ),
                 sync_asset_handles.run_if(any_with_component::<AssetRoot>),
-                sync_ui.run_if(any_with_component::<FieldListener>),
+                (
+                    sync_ui.run_if(any_with_component::<FieldListener>),
+                    sync_component_changes.run_if(any_with_component::<ComponentChangeListener>),
+                    decay_change_highlights.run_if(any_with_component::<ChangeHighlight>),
+                    sync_change_ticks.run_if(any_with_component::<ChangeTicksListener>),
+                    prune_inspector_edits.run_if(|edits: Res<InspectorEdits>| !edits.0.is_empty()),
+                ),
+                sync_image_thumbnails.run_if(
+                    any_with_component::<ImageThumbnail>.and(resource_exists::<Assets<Image>>),
+                ),
                 (
                     unfocus_text_input_on_keys.run_if(resource_changed::<ButtonInput<KeyCode>>),
                     left_align_editors.run_if(resource_removed::<FocusedTextInput>),
@@ -6563,8 +11915,17 @@ pub(super) fn plugin(app: &mut App) {
                     .chain(),
                 wait_for_birth.run_if(any_with_component::<WaitForBirth>),
                 sync_names.run_if(any_with_component::<EntityRoot>),
-                sync_visibility.run_if(
-                    any_with_component::<FieldListener>.or(any_with_component::<EntityRoot>),
+                (
+                    sync_visibility.run_if(
+                        any_with_component::<FieldListener>.or(any_with_component::<EntityRoot>),
+                    ),
+                    sync_virtual_item_heights.run_if(any_with_component::<VirtualItemHeight>),
+                    sync_virtual_lists.run_if(any_with_component::<VirtualList>),
+                ),
+                (clipboard_hotkeys, scene_hotkeys).run_if(
+                    resource_exists::<SelectedInspector>
+                        .and(resource_changed::<ButtonInput<KeyCode>>)
+                        .and(not(resource_exists::<FocusedTextInput>)),
                 ),
                 hotkey_forwarder.run_if(
                     resource_exists::<SelectedInspector>
@@ -6583,6 +11944,11 @@ pub(super) fn plugin(app: &mut App) {
             ),
         )
         .init_resource::<FieldPathCache>()
+        .init_resource::<EntityChanges>()
+        .init_resource::<InspectorEdits>()
+        .init_resource::<EventDrafts>()
+        .init_resource::<Clipboard>()
+        .init_resource::<SceneFiles>()
         .insert_resource(bevy_cosmic_edit::CursorPluginDisabled)
         .add_observer(
             |event: Trigger<RemoveTarget>, parents: Query<&Parent>, mut commands: Commands| {
@@ -6613,3 +11979,849 @@ pub(super) fn plugin(app: &mut App) {
             })
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clipboard::InMemoryClipboard, reflect::RegisterReflectEvent};
+
+    #[derive(Component)]
+    struct Existing;
+
+    #[derive(Component)]
+    struct BrandNew;
+
+    #[test]
+    fn brand_new_component_on_existing_entity_is_synced() {
+        let mut app = App::new();
+        app.init_resource::<EntityChanges>()
+            .add_systems(Update, (track_entity_changes, sync_components).chain());
+        let entity = app.world_mut().spawn(Existing).id();
+        let existing = app.world().component_id::<Existing>().unwrap();
+        let root = app
+            .world_mut()
+            .spawn((
+                EntityRoot {
+                    entity,
+                    components: HashSet::from([existing]),
+                    name: default(),
+                },
+                SyncComponents,
+            ))
+            .id();
+        app.update();
+        app.update();
+        app.world_mut().entity_mut(entity).insert(BrandNew);
+        app.update();
+        let brand_new = app.world().component_id::<BrandNew>().unwrap();
+        assert!(app
+            .world()
+            .get::<EntityRoot>(root)
+            .unwrap()
+            .components
+            .contains(&brand_new));
+    }
+
+    #[test]
+    fn same_frame_spawn_and_despawn_without_components_is_tracked() {
+        let mut app = App::new();
+        app.init_resource::<EntityChanges>()
+            .add_systems(Update, track_entity_changes);
+        let despawned = app.world_mut().spawn_empty().id();
+        app.update();
+        let mut last_generation = None;
+        app.world()
+            .resource::<EntityChanges>()
+            .changed_since(&mut last_generation);
+        app.world_mut().despawn(despawned);
+        let spawned = app.world_mut().spawn_empty().id();
+        app.update();
+        let entity_changes = app.world().resource::<EntityChanges>();
+        let changed = entity_changes.changed_since(&mut last_generation).unwrap();
+        assert!(changed.contains(&despawned));
+        assert!(changed.contains(&spawned));
+    }
+
+    #[derive(Component, Reflect, Default, Clone, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn press_hotkey(world: &mut World, keys: &[KeyCode]) -> Option<String> {
+        let mut input = world.resource_mut::<ButtonInput<KeyCode>>();
+        input.reset_all();
+        for &key in keys {
+            input.press(key);
+        }
+        clipboard_hotkeys(world);
+        world.resource_mut::<Clipboard>().get()
+    }
+
+    #[test]
+    fn inspection_targets_display_like_jump_to_tuples() {
+        let root = InspectionTarget {
+            root: InspectionTargetRoot::Asset,
+            target: None,
+        };
+        assert_eq!(root.to_string(), r#""asset""#);
+        let entity = InspectionTarget::from((InspectionTargetRoot::Entity, "player"));
+        assert_eq!(entity.to_string(), r#"("entity", "player")"#);
+        let resource = InspectionTarget::from((InspectionTargetRoot::Resource, "Health", ".max"));
+        assert_eq!(resource.to_string(), r#"("resource", "Health", ".max")"#);
+        let field =
+            InspectionTarget::from((InspectionTargetRoot::Entity, "player", "Health", ".current"));
+        assert_eq!(
+            field.to_string(),
+            r#"("entity", "player", "Health", ".current")"#
+        );
+    }
+
+    #[test]
+    fn hotkeys_copy_entity_ids_names_and_targets() {
+        let mut world = World::new();
+        world.insert_resource(Clipboard::new(InMemoryClipboard::default()));
+        world.init_resource::<ButtonInput<KeyCode>>();
+        let entity = world.spawn(Name::new("player")).id();
+        world.spawn((Hovered, HotkeyTarget::Entity(entity)));
+        assert_eq!(
+            press_hotkey(&mut world, &[KeyCode::ControlLeft, KeyCode::KeyC]),
+            Some(entity.to_string())
+        );
+        assert_eq!(
+            press_hotkey(
+                &mut world,
+                &[KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyC]
+            ),
+            Some("player".to_string())
+        );
+        assert_eq!(
+            press_hotkey(
+                &mut world,
+                &[KeyCode::ControlLeft, KeyCode::AltLeft, KeyCode::KeyC]
+            ),
+            Some(format!(r#"("entity", "{entity}")"#))
+        );
+    }
+
+    #[test]
+    fn hotkeys_copy_and_paste_fields() {
+        let mut world = World::new();
+        world.insert_resource(Clipboard::new(InMemoryClipboard::default()));
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world
+            .spawn(Health {
+                current: 3.,
+                max: 10.,
+            })
+            .id();
+        let target = AccessoryTarget::Component {
+            owner: ComponentOwnerType::Entity(entity),
+            component: world.component_id::<Health>().unwrap(),
+        };
+        world.spawn((
+            Hovered,
+            HotkeyTarget::Field {
+                target,
+                path: ParsedPath::parse(".max").unwrap(),
+            },
+        ));
+        assert_eq!(
+            press_hotkey(&mut world, &[KeyCode::ControlLeft, KeyCode::KeyC]),
+            Some("10.0".to_string())
+        );
+        assert_eq!(
+            press_hotkey(
+                &mut world,
+                &[KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyC]
+            ),
+            Some(".max".to_string())
+        );
+        assert_eq!(
+            press_hotkey(
+                &mut world,
+                &[KeyCode::ControlLeft, KeyCode::AltLeft, KeyCode::KeyC]
+            ),
+            Some(format!(r#"("entity", "{entity}", "Health", ".max")"#))
+        );
+        world.resource_mut::<Clipboard>().set("5.0".to_string());
+        press_hotkey(&mut world, &[KeyCode::ControlLeft, KeyCode::KeyV]);
+        assert_eq!(world.get::<Health>(entity).unwrap().max, 5.);
+    }
+
+    #[test]
+    fn hotkeys_copy_resource_fields() {
+        let mut world = World::new();
+        world.insert_resource(Clipboard::new(InMemoryClipboard::default()));
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Score>();
+        world.insert_resource(Score(7));
+        let target = AccessoryTarget::Component {
+            owner: ComponentOwnerType::Resource,
+            component: world.resource_id::<Score>().unwrap(),
+        };
+        world.spawn((
+            Hovered,
+            HotkeyTarget::Field {
+                target,
+                path: ParsedPath(vec![]),
+            },
+        ));
+        assert_eq!(
+            press_hotkey(&mut world, &[KeyCode::ControlLeft, KeyCode::KeyC]),
+            Some("(7)".to_string())
+        );
+        assert_eq!(
+            press_hotkey(
+                &mut world,
+                &[KeyCode::ControlLeft, KeyCode::AltLeft, KeyCode::KeyC]
+            ),
+            Some(r#"("resource", "Score")"#.to_string())
+        );
+    }
+
+    #[derive(Asset, Reflect)]
+    struct Note(String);
+
+    #[test]
+    fn hotkeys_copy_asset_handle_names() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Note>()
+            .register_asset_reflect::<Note>()
+            .insert_resource(Clipboard::new(InMemoryClipboard::default()))
+            .init_resource::<ButtonInput<KeyCode>>();
+        let world = app.world_mut();
+        let handle = world
+            .resource_mut::<Assets<Note>>()
+            .add(Note("hello".to_string()))
+            .id()
+            .untyped();
+        world.spawn((
+            Hovered,
+            HotkeyTarget::Field {
+                target: AccessoryTarget::Asset {
+                    asset: TypeId::of::<Note>(),
+                    handle,
+                },
+                path: ParsedPath(vec![]),
+            },
+        ));
+        let UntypedAssetId::Index { index, .. } = handle else {
+            panic!("added assets are indexed");
+        };
+        let name = format!("{:04X}", index.to_bits());
+        assert_eq!(
+            press_hotkey(
+                world,
+                &[KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyC]
+            ),
+            Some(name.clone())
+        );
+        assert_eq!(
+            press_hotkey(
+                world,
+                &[KeyCode::ControlLeft, KeyCode::AltLeft, KeyCode::KeyC]
+            ),
+            Some(format!(r#"("asset", "Note", "{name}")"#))
+        );
+    }
+
+    #[test]
+    fn exported_entity_scene_imports_back() {
+        let mut world = World::new();
+        let world = &mut world;
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let directory = std::env::temp_dir().join(format!("aalo_scenes_{}", std::process::id()));
+        world.insert_resource(SceneFiles::new(&directory));
+        let health = Health {
+            current: 3.,
+            max: 10.,
+        };
+        let entity = world.spawn(health.clone()).id();
+        export_hotkey_target(world, HotkeyTarget::Entity(entity), false).unwrap();
+        import_hotkey_target(world, Some(HotkeyTarget::Entity(entity))).unwrap();
+        let _ = std::fs::remove_dir_all(directory);
+        let mut healths = world.query::<&Health>();
+        assert_eq!(
+            healths
+                .iter(world)
+                .filter(|&imported| *imported == health)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn named_entities_export_to_stable_files() {
+        let mut world = World::new();
+        let named = world.spawn(Name::new("player")).id();
+        let unnamed = world.spawn_empty().id();
+        assert_eq!(entity_scene_name(&world, named), "player");
+        assert_eq!(entity_scene_name(&world, unnamed), unnamed.to_string());
+    }
+
+    #[test]
+    fn values_match_numbers_bools_and_text() {
+        assert!(value_matches(&0.1_f32, "0.1"));
+        assert!(value_matches(&5_i32, "5.0"));
+        assert!(!value_matches(&5_i32, "6"));
+        assert!(value_matches(&true, "true"));
+        assert!(!value_matches(&true, "1"));
+        assert!(value_matches(&"Iron Sword".to_string(), "sword"));
+        assert!(value_matches(&Name::new("Player One"), "player"));
+        assert!(!value_matches(&"shield".to_string(), "sword"));
+    }
+
+    #[derive(Reflect)]
+    struct Item {
+        label: String,
+        count: u32,
+    }
+
+    #[derive(Reflect)]
+    enum Slot {
+        Held(u32),
+    }
+
+    #[derive(Reflect)]
+    struct Inventory {
+        items: Vec<Item>,
+        slot: Slot,
+    }
+
+    fn value_match_paths(reflect: &dyn PartialReflect, value: &str) -> Vec<String> {
+        let mut matches = vec![];
+        find_value_matches(reflect, value, &mut vec![], &mut matches);
+        matches
+            .into_iter()
+            .map(|(path, _)| path.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn value_matches_are_found_by_path() {
+        let inventory = Inventory {
+            items: vec![
+                Item {
+                    label: "sword".to_string(),
+                    count: 1,
+                },
+                Item {
+                    label: "arrow".to_string(),
+                    count: 3,
+                },
+            ],
+            slot: Slot::Held(3),
+        };
+        assert_eq!(value_match_paths(&inventory, "SWORD"), [".items[0].label"]);
+        assert_eq!(
+            value_match_paths(&inventory, "3"),
+            [".items[1].count", ".slot.0"]
+        );
+        assert!(value_match_paths(&inventory, "shield").is_empty());
+        assert_eq!(
+            value_match_paths(&vec![0_u32; MAX_VALUE_MATCHES * 2], "0").len(),
+            MAX_VALUE_MATCHES
+        );
+    }
+
+    #[test]
+    fn value_search_covers_assets() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Note>()
+            .register_asset_reflect::<Note>();
+        let world = app.world_mut();
+        let handle = world
+            .resource_mut::<Assets<Note>>()
+            .add(Note("shopping list".to_string()))
+            .id()
+            .untyped();
+        let assets = MutableBTreeMap::new();
+        assets.lock_mut().insert_cloned(
+            TypeId::of::<Note>(),
+            AssetData {
+                name: "Note",
+                expanded: default(),
+                filtered: default(),
+                handles: default(),
+            },
+        );
+        let matches = MutableVec::new();
+        world.spawn(ValueSearch {
+            value: "shopping".to_string(),
+            root: InspectionTargetRoot::Asset,
+            entities: default(),
+            resources: default(),
+            assets,
+            matches: matches.clone(),
+        });
+        search_values(world);
+        let handle = handle_name(handle, world.resource::<AssetServer>());
+        let targets = matches
+            .lock_ref()
+            .iter()
+            .map(|ValueMatch { target, .. }| target.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(targets, [format!(r#"("asset", "Note", "{handle}", ".0")"#)]);
+    }
+
+    #[test]
+    fn change_highlights_follow_field_values() {
+        let mut world = World::new();
+        let world = &mut world;
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn(Health::default()).id();
+        let target = AccessoryTarget::Component {
+            owner: ComponentOwnerType::Entity(entity),
+            component: world.component_id::<Health>().unwrap(),
+        };
+        let current = ParsedPath::parse(".current").unwrap();
+        let max = ParsedPath::parse(".max").unwrap();
+        let current_ui = world.spawn_empty().id();
+        let max_ui = world.spawn_empty().id();
+        assert!(!changed_since_seen(world, target, &current, current_ui));
+        assert!(!changed_since_seen(world, target, &max, max_ui));
+        world.increment_change_tick();
+        assert!(!changed_since_seen(world, target, &current, current_ui));
+        world.get_mut::<Health>(entity).unwrap().current = 1.;
+        assert!(changed_since_seen(world, target, &current, current_ui));
+        assert!(!changed_since_seen(world, target, &max, max_ui));
+        assert!(!changed_since_seen(world, target, &current, current_ui));
+        // writes that leave the value as it was don't count
+        world.increment_change_tick();
+        world.get_mut::<Health>(entity).unwrap().current = 1.;
+        assert!(!changed_since_seen(world, target, &current, current_ui));
+    }
+
+    #[test]
+    fn change_highlights_follow_asset_values() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Note>()
+            .register_asset_reflect::<Note>();
+        let world = app.world_mut();
+        let handle = world
+            .resource_mut::<Assets<Note>>()
+            .add(Note("eggs".to_string()));
+        let target = AccessoryTarget::Asset {
+            asset: TypeId::of::<Note>(),
+            handle: handle.id().untyped(),
+        };
+        let path = ParsedPath::parse(".0").unwrap();
+        let ui_entity = world.spawn_empty().id();
+        assert!(!changed_since_seen(world, target, &path, ui_entity));
+        assert!(!changed_since_seen(world, target, &path, ui_entity));
+        world
+            .resource_mut::<Assets<Note>>()
+            .get_mut(&handle)
+            .unwrap()
+            .0 = "milk".to_string();
+        assert!(changed_since_seen(world, target, &path, ui_entity));
+        assert!(!changed_since_seen(world, target, &path, ui_entity));
+    }
+
+    #[test]
+    fn time_controls_edit_through_time_methods() {
+        let mut world = World::new();
+        world.init_resource::<Time<Virtual>>();
+        let FieldSetter(set) = relative_speed_setter();
+        set(&mut world, &10_f64);
+        let time = world.resource::<Time<Virtual>>();
+        assert_eq!(time.relative_speed_f64(), TIME_CONTROLS_MAX_SPEED);
+    }
+
+    #[test]
+    fn read_only_propagates_to_nested_fields() {
+        let mut world = World::new();
+        let header = || HeaderData {
+            pinned: default(),
+            expanded: default(),
+        };
+        let read_only = world
+            .spawn((
+                header(),
+                FieldAttributes {
+                    read_only: true,
+                    ..default()
+                },
+            ))
+            .id();
+        let nested = world.spawn(header()).set_parent(read_only).id();
+        let input = world.spawn_empty().set_parent(nested).id();
+        let editable = world.spawn(header()).id();
+        let mut system_state = SystemState::<ClosestFieldAttributes>::new(&mut world);
+        let closest_field_attributes = system_state.get(&world);
+        assert!(closest_field_attributes.read_only(nested));
+        assert!(closest_field_attributes.read_only(input));
+        assert!(!closest_field_attributes.read_only(editable));
+    }
+
+    #[test]
+    fn copied_component_pastes_onto_other_entities() {
+        let mut world = World::new();
+        let world = &mut world;
+        world.insert_resource(Clipboard::new(InMemoryClipboard::default()));
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let health = Health {
+            current: 3.,
+            max: 10.,
+        };
+        let source = world.spawn(health.clone()).id();
+        let existing = world.spawn(Health::default()).id();
+        let missing = world.spawn_empty().id();
+        let component = world.component_id::<Health>().unwrap();
+        copy_component(world, source, component);
+        assert!(world.resource_mut::<Clipboard>().get().is_some());
+        paste_component(world, existing, default()).unwrap();
+        paste_component(world, missing, default()).unwrap();
+        assert_eq!(world.get::<Health>(existing), Some(&health));
+        assert_eq!(world.get::<Health>(missing), Some(&health));
+    }
+
+    #[test]
+    fn pasting_non_components_is_rejected() {
+        let mut world = World::new();
+        let world = &mut world;
+        world.insert_resource(Clipboard::new(InMemoryClipboard::default()));
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<f32>();
+        let entity = world.spawn_empty().id();
+        world
+            .resource_mut::<Clipboard>()
+            .set(r#"{"f32": 1.0}"#.to_string());
+        assert!(paste_component(world, entity, default()).is_err());
+        world.resource_mut::<Clipboard>().set("not ron".to_string());
+        assert!(paste_component(world, entity, default()).is_err());
+        assert_eq!(world.entity(entity).archetype().component_count(), 0);
+    }
+
+    #[test]
+    fn field_ron_round_trips() {
+        let mut world = World::new();
+        let world = &mut world;
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let source = world
+            .spawn(Health {
+                current: 3.,
+                max: 10.,
+            })
+            .id();
+        let destination = world.spawn(Health::default()).id();
+        let component = world.component_id::<Health>().unwrap();
+        let target = |entity| AccessoryTarget::Component {
+            owner: ComponentOwnerType::Entity(entity),
+            component,
+        };
+        let path = ParsedPath::parse(".max").unwrap();
+        let ron = field_ron(world, target(source), &path).unwrap();
+        assert_eq!(ron, "10.0");
+        assert!(apply_ron(world, target(destination), &path, &ron, default()).is_ok());
+        assert_eq!(world.get::<Health>(destination).unwrap().max, 10.);
+        assert!(apply_ron(world, target(destination), &path, "\"ten\"", default()).is_err());
+        assert_eq!(world.get::<Health>(destination).unwrap().max, 10.);
+    }
+
+    #[test]
+    fn oversized_integer_expressions_overflow() {
+        assert_eq!(parse_numeric::<i8>("100+27", 0), Ok(127));
+        assert!(parse_numeric::<i8>("100+28", 0).is_err());
+        assert!(parse_numeric::<i64>("2^63", 0).is_err());
+        assert!(parse_numeric::<u64>("2^64", 0).is_err());
+        assert_eq!(parse_numeric::<i64>("-2^63", 0), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn inspector_edits_are_pruned() {
+        let mut app = App::new();
+        app.register_type::<Health>()
+            .init_resource::<InspectorEdits>()
+            .add_systems(
+                Update,
+                prune_inspector_edits.run_if(|edits: Res<InspectorEdits>| !edits.0.is_empty()),
+            );
+        let world = app.world_mut();
+        let despawned = world.spawn(Health::default()).id();
+        let removed = world.spawn(Health::default()).id();
+        let kept = world.spawn(Health::default()).id();
+        let component = world.component_id::<Health>().unwrap();
+        let path = ParsedPath::parse(".max").unwrap();
+        for entity in [despawned, removed, kept] {
+            let target = AccessoryTarget::Component {
+                owner: ComponentOwnerType::Entity(entity),
+                component,
+            };
+            edit_field(world, target, &path, &1f32, default()).unwrap();
+        }
+        assert_eq!(world.resource::<InspectorEdits>().0.len(), 3);
+        world.despawn(despawned);
+        world.entity_mut(removed).remove::<Health>();
+        app.update();
+        let edits = app.world().resource::<InspectorEdits>();
+        assert_eq!(
+            edits.0.keys().collect::<Vec<_>>(),
+            [&(ComponentOwnerType::Entity(kept), component)]
+        );
+    }
+
+    #[derive(Event, Reflect, Default, Debug, PartialEq)]
+    struct Damage(f32);
+
+    #[test]
+    fn event_drafts_are_sent() {
+        let mut app = App::new();
+        app.init_resource::<EventDrafts>()
+            .add_event::<Damage>()
+            .register_reflect_event::<Damage>();
+        let world = app.world_mut();
+        let events = world.components().resource_id::<Events<Damage>>().unwrap();
+        assert!(reset_event_draft(world, events));
+        world
+            .resource_mut::<EventDrafts>()
+            .get_mut(&events)
+            .unwrap()
+            .as_partial_reflect_mut()
+            .apply(&Damage(2.));
+        send_event_draft(world, events).unwrap();
+        send_event_draft(world, events).unwrap();
+        let sent = world.resource::<Events<Damage>>();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent.iter_current_update_events().next(), Some(&Damage(2.)));
+    }
+
+    fn conditioned_system() {}
+
+    fn always() -> bool {
+        true
+    }
+
+    #[test]
+    fn schedules_keep_run_conditions_after_running() {
+        let mut app = App::new();
+        app.add_systems(Update, conditioned_system.run_if(always))
+            .add_systems(Main, sync_schedules.after(Main::run_main));
+        sync_schedules(app.world());
+        app.update();
+        app.update();
+        let schedules = SCHEDULES.lock_ref();
+        let update = schedules.get("Update").unwrap();
+        let systems = update.systems.lock_ref();
+        let system = systems
+            .iter()
+            .find(|system| system.name.ends_with("conditioned_system"))
+            .unwrap();
+        assert!(system
+            .conditions
+            .as_slice()
+            .iter()
+            .any(|condition| condition.ends_with("always")));
+        assert!(system.last_run.get().is_some());
+        assert!(update.initialized.get());
+    }
+
+    #[test]
+    fn query_filter_rows_follow_archetype_changes() {
+        let mut app = App::new();
+        app.init_resource::<EntityChanges>().add_systems(
+            Update,
+            (track_entity_changes, sync_entity_query_filters).chain(),
+        );
+        let matching = app.world_mut().spawn(Existing).id();
+        let other = app.world_mut().spawn(BrandNew).id();
+        let filters = EntityQueryFilters(Arc::new(Mutex::new(EntityQueryFiltersState {
+            filters: vec![EntityQueryFilter::new::<With<Existing>>()],
+            ..default()
+        })));
+        app.world_mut().spawn(filters.clone());
+        app.update();
+        let (matching_row, other_row) = (filters.row(matching), filters.row(other));
+        assert!(matching_row.get());
+        assert!(!other_row.get());
+        app.update();
+        app.world_mut().entity_mut(other).insert(Existing);
+        app.world_mut().entity_mut(matching).remove::<Existing>();
+        app.update();
+        assert!(!matching_row.get());
+        assert!(other_row.get());
+        app.world_mut().entity_mut(other).despawn();
+        app.update();
+        assert!(!filters.0.lock().unwrap().rows.contains_key(&other));
+    }
+
+    #[test]
+    fn watched_targets_resolve_once_their_entity_spawns() {
+        let mut app = App::new();
+        app.init_resource::<EntityChanges>()
+            .add_systems(Update, (track_entity_changes, sync_watched_targets).chain());
+        let resolved = Mutable::new(None);
+        app.world_mut().spawn(WatchedTarget {
+            target: InspectionTarget {
+                root: InspectionTargetRoot::Entity,
+                target: Some(InspectionTargetInner::Multi(InspectionTargetMutliField {
+                    name: "player".to_string(),
+                    field: Some(InspectionTargetField {
+                        field: "Health".to_string(),
+                        path: None,
+                    }),
+                })),
+            },
+            resolved: resolved.clone(),
+        });
+        app.update();
+        assert!(resolved.lock_ref().is_none());
+        let player = app
+            .world_mut()
+            .spawn((
+                Name::new("player"),
+                Health {
+                    current: 1.,
+                    max: 1.,
+                },
+            ))
+            .id();
+        app.update();
+        assert!(matches!(
+            resolved.get_cloned(),
+            Some((FieldElementInput::Component { owner: ComponentOwnerType::Entity(entity), .. }, ..)) if entity == player
+        ));
+        app.world_mut().entity_mut(player).despawn();
+        app.update();
+        assert!(resolved.lock_ref().is_none());
+    }
+
+    #[test]
+    fn watched_targets_only_resolve_again_when_their_target_changes() {
+        let mut app = App::new();
+        app.init_resource::<EntityChanges>()
+            .add_systems(Update, (track_entity_changes, sync_watched_targets).chain());
+        let world = app.world_mut();
+        let player = world
+            .spawn(Health {
+                current: 1.,
+                max: 1.,
+            })
+            .id();
+        assert_eq!(parse_entity(&player.to_string()), Some(player));
+        let by_id = Mutable::new(None);
+        world.spawn(WatchedTarget {
+            target: InspectionTarget::from((
+                InspectionTargetRoot::Entity,
+                player.to_string().as_str(),
+                "Health",
+                ".max",
+            )),
+            resolved: by_id.clone(),
+        });
+        let score = Mutable::new(None);
+        world.spawn(WatchedTarget {
+            target: InspectionTarget::from((InspectionTargetRoot::Resource, "Score", "")),
+            resolved: score.clone(),
+        });
+        app.update();
+        assert!(by_id.lock_ref().is_some());
+        assert!(score.lock_ref().is_none());
+        // unrelated changes leave resolutions alone
+        by_id.set(None);
+        app.world_mut().spawn(Name::new("bystander"));
+        app.update();
+        assert!(by_id.lock_ref().is_none());
+        app.world_mut()
+            .entity_mut(player)
+            .insert(Name::new("player"));
+        app.update();
+        assert!(by_id.lock_ref().is_some());
+        app.world_mut().insert_resource(Score(7));
+        app.update();
+        assert!(score.lock_ref().is_some());
+        app.world_mut().remove_resource::<Score>();
+        app.update();
+        assert!(score.lock_ref().is_none());
+    }
+
+    fn virtual_list(len: usize) -> Mutex<VirtualListState<usize>> {
+        let mut state = VirtualListState::new(
+            10.,
+            |item: &usize, part: &ProgressPart| matches!(part, ProgressPart::Field(field) if *field == item.to_string()),
+        );
+        for i in 0..len {
+            state.apply_diff(VecDiff::Push { value: i });
+        }
+        Mutex::new(state)
+    }
+
+    #[test]
+    fn growing_virtual_list_window_is_capped() {
+        let list = virtual_list(1000);
+        let state = list.lock().unwrap();
+        let (start, end) = state.window;
+        assert!(end - start <= VIRTUALIZATION_THRESHOLD);
+        assert_eq!(state.visible.lock_ref().len(), end - start);
+    }
+
+    #[test]
+    fn virtual_list_windows_viewport() {
+        let list = virtual_list(1000);
+        list.sync(1000., 1100.);
+        let state = list.lock().unwrap();
+        assert_eq!(state.window, (100, 110));
+        assert_eq!(
+            state
+                .visible
+                .lock_ref()
+                .iter()
+                .map(|(i, _)| *i)
+                .collect::<Vec<_>>(),
+            (100..110).collect::<Vec<_>>()
+        );
+        assert_eq!(state.top_spacer.get(), 1000.);
+        assert_eq!(state.bottom_spacer.get(), 8900.);
+    }
+
+    #[test]
+    fn virtual_list_reveals_target_outside_window() {
+        let list = virtual_list(1000);
+        list.sync(0., 100.);
+        list.reveal(ProgressPart::Field("500".to_string()));
+        list.sync(0., 100.);
+        assert_eq!(list.lock().unwrap().window, (500, 501));
+        assert_eq!(list.lock().unwrap().top_spacer.get(), 5000.);
+        // scrolled to the target
+        list.sync(4950., 5050.);
+        list.sync(0., 100.);
+        assert_eq!(list.lock().unwrap().window, (0, 10));
+    }
+}
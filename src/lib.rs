@@ -5,10 +5,15 @@ use bevy_utils::prelude::*;
 use haalka::prelude::*;
 use std::sync::{Arc, Mutex};
 
+pub mod attributes;
+pub mod clipboard;
 pub mod defaults;
+pub mod expression;
 pub mod globals;
 pub mod inspector;
 pub mod reflect;
+pub mod scene;
+pub mod search;
 pub mod style;
 pub mod utils;
 pub mod widgets;
@@ -140,6 +145,9 @@ impl<WorldFlag: Send + Sync + 'static> Plugin for AaloPlugin<WorldFlag> {
                                 inspector
                                     .resources(RESOURCES.clone())
                                     .assets(ASSETS.clone())
+                                    .events(EVENTS.clone())
+                                    .observers(OBSERVERS.clone())
+                                    .schedules(SCHEDULES.clone())
                                     .apply(|mut entity_inspector| {
                                         for f in transformers.lock().unwrap().drain(..) {
                                             entity_inspector = f(entity_inspector)
@@ -158,12 +166,18 @@ impl<WorldFlag: Send + Sync + 'static> Plugin for AaloPlugin<WorldFlag> {
             );
         }
     }
+
+    fn cleanup(&self, app: &mut App) {
+        // bevy moves run conditions out of schedules when they first run
+        inspector::sync_schedules(app.world());
+    }
 }
 
 pub mod prelude {
     pub use super::AaloPlugin;
     pub use crate::{
         inspector::{register_frontend, FieldListener, Inspector, TargetField},
+        reflect::{ReflectEvent, RegisterReflectEvent},
         utils::InspectorMarker,
     };
 }
@@ -1,8 +1,9 @@
 use std::any::TypeId;
 
+use bevy_app::prelude::*;
 use bevy_asset::{ReflectAsset, UntypedAssetId, UntypedHandle};
-use bevy_ecs::{component::ComponentId, prelude::*};
-use bevy_reflect::{prelude::*, ReflectFromPtr};
+use bevy_ecs::{component::ComponentId, event::Events, prelude::*};
+use bevy_reflect::{prelude::*, FromType, GetTypeRegistration, ReflectFromPtr, Typed};
 
 pub fn reflect_component(
     world: &mut World,
@@ -90,6 +91,22 @@ pub fn with_reflect_component_mut<T>(
         })
 }
 
+// doesn't trigger change detection
+pub fn with_reflect_component_mut_silent<T>(
+    world: &mut World,
+    entity: Entity,
+    component: ComponentId,
+    f: impl FnOnce(&mut dyn Reflect) -> T,
+) -> Option<T> {
+    world
+        .get_entity_mut(entity)
+        .ok()
+        .and_then(|ref mut entity| {
+            reflect_component_mut(entity, component)
+                .map(|mut reflect| f(reflect.bypass_change_detection()))
+        })
+}
+
 pub fn reflect_resource(world: &mut World, component: ComponentId) -> Option<&dyn Reflect> {
     if let Some((type_id, type_registry)) = world
         .components()
@@ -156,6 +173,15 @@ pub fn with_reflect_resource_mut<T>(
     reflect_resource_mut(world, component).map(|mut reflect| f(reflect.as_reflect_mut()))
 }
 
+// doesn't trigger change detection
+pub fn with_reflect_resource_mut_silent<T>(
+    world: &mut World,
+    component: ComponentId,
+    f: impl FnOnce(&mut dyn Reflect) -> T,
+) -> Option<T> {
+    reflect_resource_mut(world, component).map(|mut reflect| f(reflect.bypass_change_detection()))
+}
+
 pub fn reflect_asset(
     world: &mut World,
     asset: TypeId,
@@ -208,3 +234,49 @@ pub fn with_reflect_asset_mut<T>(
 ) -> Option<T> {
     reflect_asset_mut(world, asset, handle).map(f)
 }
+
+/// Type data for sending events from their reflected value, e.g. `#[reflect(Event)]` or [`RegisterReflectEvent::register_reflect_event`].
+#[derive(Clone)]
+pub struct ReflectEvent {
+    send: fn(&mut World, &dyn PartialReflect) -> Result<(), String>,
+}
+
+impl ReflectEvent {
+    /// Send `event` through its `Events<T>` resource, like [`World::send_event`].
+    pub fn send(&self, world: &mut World, event: &dyn PartialReflect) -> Result<(), String> {
+        (self.send)(world, event)
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        Self {
+            send: |world, event| {
+                let event = E::from_reflect(event)
+                    .ok_or_else(|| format!("failed to construct {}", E::type_path()))?;
+                world
+                    .send_event(event)
+                    .map(|_| ())
+                    .ok_or_else(|| format!("{} was not added with `add_event`", E::type_path()))
+            },
+        }
+    }
+}
+
+pub trait RegisterReflectEvent {
+    /// Register `Events<E>` so the inspector lists it, and [`ReflectEvent`] for `E` so it can send them.
+    fn register_reflect_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + FromReflect + Typed + GetTypeRegistration;
+}
+
+impl RegisterReflectEvent for App {
+    fn register_reflect_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + FromReflect + Typed + GetTypeRegistration,
+    {
+        self.register_type::<E>()
+            .register_type::<Events<E>>()
+            .register_type_data::<E, ReflectEvent>()
+    }
+}
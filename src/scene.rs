@@ -0,0 +1,176 @@
+// exporting entities and resources to `.scn.ron` files and importing them back, scene files aren't available on wasm
+
+use bevy_core::Name;
+use bevy_ecs::{entity::EntityHashMap, prelude::*};
+use bevy_hierarchy::prelude::*;
+use bevy_log::prelude::*;
+use bevy_scene::{serde::SceneDeserializer, DynamicScene, DynamicSceneBuilder, SceneFilter};
+use serde::de::DeserializeSeed;
+use std::{
+    any::TypeId,
+    path::{Path, PathBuf},
+};
+
+pub const SCENE_EXTENSION: &str = "scn.ron";
+
+/// Where the inspector exports scenes to; insert this resource to change the directory.
+#[derive(Resource, Clone, Debug)]
+pub struct SceneFiles {
+    pub directory: PathBuf,
+    last_exported: Option<PathBuf>,
+}
+
+impl SceneFiles {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            last_exported: None,
+        }
+    }
+
+    pub fn last_exported(&self) -> Option<&Path> {
+        self.last_exported.as_deref()
+    }
+
+    pub fn path(&self, name: &str) -> PathBuf {
+        let name = name
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        self.directory.join(format!("{name}.{SCENE_EXTENSION}"))
+    }
+}
+
+impl Default for SceneFiles {
+    fn default() -> Self {
+        Self::new("scenes")
+    }
+}
+
+fn descendants(world: &World, entity: Entity) -> Vec<Entity> {
+    let mut descendants = vec![entity];
+    let mut i = 0;
+    while let Some(&cur) = descendants.get(i) {
+        if let Some(children) = world.get::<Children>(cur) {
+            descendants.extend(children.iter().copied());
+        }
+        i += 1;
+    }
+    descendants
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_file(path: &Path, contents: String) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, contents).map_err(|e| format!("failed to write {}: {e}", path.display()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_file(_: &Path, _: String) -> Result<(), String> {
+    Err("scene files aren't supported on wasm".to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_file(_: &Path) -> Result<String, String> {
+    Err("scene files aren't supported on wasm".to_string())
+}
+
+fn write_scene(world: &mut World, scene: &DynamicScene, path: &Path) -> Result<(), String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let ron = scene
+        .serialize(&type_registry.read())
+        .map_err(|e| e.to_string())?;
+    write_file(path, ron)?;
+    info!("exported scene to {}", path.display());
+    if let Some(mut scene_files) = world.get_resource_mut::<SceneFiles>() {
+        scene_files.last_exported = Some(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Export `entity`, optionally with its descendants, to a scene file at `path`.
+pub fn export_entity(
+    world: &mut World,
+    entity: Entity,
+    with_descendants: bool,
+    path: &Path,
+) -> Result<(), String> {
+    let entities = if with_descendants {
+        descendants(world, entity)
+    } else {
+        vec![entity]
+    };
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .deny_all_resources()
+        .extract_entities(entities.into_iter())
+        .build();
+    // hierarchy components pointing outside of the scene would dangle on import
+    for dynamic_entity in scene.entities.iter_mut() {
+        if dynamic_entity.entity == entity || !with_descendants {
+            dynamic_entity.components.retain(|component| {
+                !component.represents::<Parent>()
+                    && (with_descendants || !component.represents::<Children>())
+            });
+        }
+    }
+    write_scene(world, &scene, path)
+}
+
+/// Export the resources with the given [`TypeId`]s to a scene file at `path`.
+pub fn export_resources(
+    world: &mut World,
+    resources: impl IntoIterator<Item = TypeId>,
+    path: &Path,
+) -> Result<(), String> {
+    let filter = resources
+        .into_iter()
+        .fold(SceneFilter::deny_all(), SceneFilter::allow_by_id);
+    let scene = DynamicSceneBuilder::from_world(world)
+        .deny_all_components()
+        .with_resource_filter(filter)
+        .extract_resources()
+        .build();
+    write_scene(world, &scene, path)
+}
+
+/// Spawn the entities and insert the resources of the scene file at `path`; entities are always spawned anew, so
+/// importing a scene of entities that still exist duplicates them.
+pub fn import_scene(world: &mut World, path: &Path) -> Result<(), String> {
+    let ron = read_file(path)?;
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let scene = {
+        let type_registry = type_registry.read();
+        let mut deserializer = ron::Deserializer::from_str(&ron).map_err(|e| e.to_string())?;
+        SceneDeserializer {
+            type_registry: &type_registry,
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|e| e.to_string())?
+    };
+    scene
+        .write_to_world(world, &mut EntityHashMap::default())
+        .map_err(|e| e.to_string())?;
+    info!("imported scene from {}", path.display());
+    Ok(())
+}
+
+// entity ids aren't stable across runs, so named entities export to the same file every session
+pub(crate) fn entity_scene_name(world: &World, entity: Entity) -> String {
+    match world.get::<Name>(entity) {
+        Some(name) => name.to_string(),
+        None => entity.to_string(),
+    }
+}
@@ -0,0 +1,230 @@
+// query language for the inspector's search, e.g. `with:Transform without:Camera name:player* enemy` or `value:0`
+
+use disqualified::ShortName;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SearchTerm {
+    /// Entity has a component matching this name.
+    With(String),
+    /// Entity doesn't have a component matching this name.
+    Without(String),
+    /// Name matches this glob, e.g. `player*`.
+    Name(String),
+    /// Entity has a component matching this name which changed since the last frame.
+    Changed(String),
+    /// A string field contains, or a numeric or boolean field equals, this; listed separately rather than filtering.
+    Value(String),
+    /// Name fuzzy matches this.
+    Fuzzy(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+}
+
+impl SearchQuery {
+    /// Terms are separated by whitespace, which double quotes escape, e.g. `name:"big enemy"`.
+    pub fn parse(search: &str) -> Self {
+        let terms = split_terms(search)
+            .into_iter()
+            .map(|term| match term.split_once(':') {
+                Some((prefix, value)) if !value.is_empty() => {
+                    let value = value.to_string();
+                    match prefix.to_lowercase().as_str() {
+                        "with" => SearchTerm::With(value),
+                        "without" => SearchTerm::Without(value),
+                        "name" => SearchTerm::Name(value),
+                        "changed" => SearchTerm::Changed(value),
+                        "value" => SearchTerm::Value(value),
+                        _ => SearchTerm::Fuzzy(term),
+                    }
+                }
+                _ => SearchTerm::Fuzzy(term),
+            })
+            .collect();
+        Self { terms }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// The fuzzy terms joined by spaces, empty if there are none.
+    pub fn fuzzy(&self) -> String {
+        self.terms
+            .iter()
+            .filter_map(|term| match term {
+                SearchTerm::Fuzzy(fuzzy) => Some(fuzzy.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn name_globs(&self) -> impl Iterator<Item = &str> {
+        self.terms.iter().filter_map(|term| match term {
+            SearchTerm::Name(glob) => Some(glob.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        self.terms.iter().find_map(|term| match term {
+            SearchTerm::Value(value) => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Whether the query has terms that need the `World` to evaluate, i.e. `with:`, `without:`, or `changed:`.
+    pub fn is_structural(&self) -> bool {
+        self.terms.iter().any(|term| {
+            matches!(
+                term,
+                SearchTerm::With(_) | SearchTerm::Without(_) | SearchTerm::Changed(_)
+            )
+        })
+    }
+}
+
+// splits on whitespace outside of double quotes, dropping the quotes
+fn split_terms(search: &str) -> Vec<String> {
+    let mut terms = vec![];
+    let mut term = String::new();
+    let mut quoted = false;
+    for c in search.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => terms.push(std::mem::take(&mut term)),
+            c => term.push(c),
+        }
+    }
+    terms.push(term);
+    terms.retain(|term| !term.trim().is_empty());
+    terms
+}
+
+/// Case insensitive glob match supporting `*` and `?`.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase().chars().collect::<Vec<_>>();
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    // position of the last `*` and the text position it was tried at
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, star_t)) => {
+                    p = star + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Whether a type path matches a `with:`, `without:`, or `changed:` term, either in full or by its short name.
+pub fn type_path_matches(pattern: &str, type_path: &str) -> bool {
+    glob_match(pattern, type_path) || glob_match(pattern, &ShortName(type_path).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_terms() {
+        assert_eq!(
+            SearchQuery::parse(
+                "with:Transform WITHOUT:Camera name:player* changed:Health value:0 enemy"
+            )
+            .terms,
+            vec![
+                SearchTerm::With("Transform".to_string()),
+                SearchTerm::Without("Camera".to_string()),
+                SearchTerm::Name("player*".to_string()),
+                SearchTerm::Changed("Health".to_string()),
+                SearchTerm::Value("0".to_string()),
+                SearchTerm::Fuzzy("enemy".to_string()),
+            ]
+        );
+        // unknown prefixes and empty values are fuzzy
+        assert_eq!(
+            SearchQuery::parse("foo:bar with:").terms,
+            vec![
+                SearchTerm::Fuzzy("foo:bar".to_string()),
+                SearchTerm::Fuzzy("with:".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_quoted_terms() {
+        let query = SearchQuery::parse(r#"name:"big enemy" "red  player" value:"a b""#);
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Name("big enemy".to_string()),
+                SearchTerm::Fuzzy("red  player".to_string()),
+                SearchTerm::Value("a b".to_string()),
+            ]
+        );
+        assert_eq!(query.fuzzy(), "red  player");
+        assert_eq!(query.value(), Some("a b"));
+        // unterminated quotes run to the end
+        assert_eq!(
+            SearchQuery::parse(r#"name:"big enemy"#).terms,
+            vec![SearchTerm::Name("big enemy".to_string())]
+        );
+    }
+
+    #[test]
+    fn parse_empty() {
+        for search in ["", "   ", r#""""#, r#"" ""#] {
+            let query = SearchQuery::parse(search);
+            assert!(query.is_empty(), "{search}");
+            assert_eq!(query.fuzzy(), "");
+            assert!(!query.is_structural());
+            assert_eq!(query.value(), None);
+        }
+    }
+
+    #[test]
+    fn globs() {
+        assert!(glob_match("player*", "Player One"));
+        assert!(glob_match("*one", "player one"));
+        assert!(glob_match("p?ayer", "player"));
+        assert!(glob_match("*a*b*", "xaxxbx"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "player"));
+        assert!(!glob_match("p?ayer", "pyer"));
+        assert!(!glob_match("player", "player one"));
+        assert!(!glob_match("*ab", "abba"));
+    }
+
+    #[test]
+    fn type_paths() {
+        let type_path = "bevy_transform::components::transform::Transform";
+        assert!(type_path_matches("Transform", type_path));
+        assert!(type_path_matches("transform", type_path));
+        assert!(type_path_matches("bevy_transform::*", type_path));
+        assert!(type_path_matches("Trans*", type_path));
+        assert!(!type_path_matches("GlobalTransform", type_path));
+        assert!(type_path_matches(
+            "Handle<Image>",
+            "bevy_asset::handle::Handle<bevy_image::image::Image>"
+        ));
+    }
+}
@@ -1,8 +1,10 @@
+use bevy_asset::prelude::*;
 use bevy_ecs::{
     prelude::*,
     system::{RunSystemOnce, SystemId, SystemParam},
 };
 use bevy_hierarchy::prelude::*;
+use bevy_image::Image;
 use bevy_math::prelude::*;
 use bevy_picking::prelude::*;
 use bevy_ui::prelude::*;
@@ -212,11 +214,21 @@ impl<'w, 's> MoveTooltipToPosition<'w, 's> {
 pub struct TooltipData {
     pub owner: Entity,
     pub text: String,
+    pub image: Option<(Handle<Image>, Vec2)>,
 }
 
 impl TooltipData {
     pub fn new(owner: Entity, text: String) -> Self {
-        Self { owner, text }
+        Self {
+            owner,
+            text,
+            image: None,
+        }
+    }
+
+    pub fn with_image(mut self, image: Handle<Image>, size: Vec2) -> Self {
+        self.image = Some((image, size));
+        self
     }
 }
 